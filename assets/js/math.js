@@ -0,0 +1,35 @@
+// Renders math in an element with KaTeX, using the same delimiters as the preview server's page.
+//
+// KaTeX's stylesheet and fonts are not bundled, so math is rendered as MathML, which browsers can
+// display without them.
+window.mdplsRenderMath = function(root) {
+    var delimiters = /\$\$([\s\S]+?)\$\$|\\\[([\s\S]+?)\\\]|\\\(([\s\S]+?)\\\)|\$([^$\n]+?)\$/g;
+    var walker = document.createTreeWalker(root, NodeFilter.SHOW_TEXT), nodes = [];
+    while (walker.nextNode()) {
+        if (!walker.currentNode.parentNode.closest('code, pre, script, style, .katex')) {
+            nodes.push(walker.currentNode);
+        }
+    }
+    nodes.forEach(function(node) {
+        var text = node.nodeValue, fragment = document.createDocumentFragment(), last = 0, match;
+        delimiters.lastIndex = 0;
+        while ((match = delimiters.exec(text)) !== null) {
+            var math = document.createElement('span');
+            try {
+                katex.render(match[1] || match[2] || match[3] || match[4], math, {
+                    displayMode: match[1] !== undefined || match[2] !== undefined,
+                    output: 'mathml',
+                    throwOnError: false
+                });
+            } catch (e) {
+                continue;
+            }
+            fragment.appendChild(document.createTextNode(text.slice(last, match.index)));
+            fragment.appendChild(math);
+            last = delimiters.lastIndex;
+        }
+        if (last === 0) return;
+        fragment.appendChild(document.createTextNode(text.slice(last)));
+        node.parentNode.replaceChild(fragment, node);
+    });
+};
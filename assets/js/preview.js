@@ -0,0 +1,64 @@
+// Client for previews that are viewed offline. It works like the client of the preview server's
+// page, but only uses assets that are bundled with mdpls. The URL of the preview server is passed
+// as the `server` query parameter, and the URL of events from the language server as `events`.
+document.addEventListener('DOMContentLoaded', function() {
+    var params = new URLSearchParams(window.location.search);
+    var server = params.get('server');
+    var preview = document.getElementById('markdown-preview');
+    var reconnectInterval = 1000;
+
+    // Content that is sent to the page subscribes to the same events, so the subscription is
+    // shared.
+    if (params.get('events')) {
+        window.mdplsEvents = new EventSource(params.get('events'));
+
+        mdplsEvents.addEventListener('theme', function(event) {
+            document.getElementById('code-style').href = 'highlight.js/styles/' + event.data + '.css';
+        });
+
+        // Custom stylesheets replace the default stylesheet. They are sent with the content.
+        mdplsEvents.addEventListener('default-style', function(event) {
+            document.getElementById('markdown-style').disabled = event.data !== 'true';
+        });
+    }
+
+    function update(html) {
+        preview.innerHTML = html;
+
+        var codeBlocks = preview.querySelectorAll('pre code');
+        for (var i = 0; i < codeBlocks.length; i++) {
+            hljs.highlightBlock(codeBlocks[i]);
+
+            // Since the github css doesn't play nice with highlight.js, we need to set the
+            // background of all `pre` elements to be the color of the inner `code` block.
+            codeBlocks[i].parentNode.style.background = (
+                getComputedStyle(codeBlocks[i]).getPropertyValue('background'));
+        }
+
+        mdplsRenderMath(preview);
+    }
+
+    function connect() {
+        var socket = new WebSocket(server.replace(/^http/, 'ws'));
+
+        socket.onopen = function() {
+            reconnectInterval = 1000;
+        };
+
+        socket.onmessage = function(event) {
+            update(event.data);
+        };
+
+        socket.onclose = function() {
+            // Close the browser window. Browsers only allow this for windows that were opened by
+            // scripts, so reconnect if the window stays open.
+            window.open('', '_self', '');
+            window.close();
+
+            setTimeout(connect, reconnectInterval);
+            reconnectInterval = Math.min(reconnectInterval * 2, 5000);
+        };
+    }
+
+    connect();
+});
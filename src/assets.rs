@@ -0,0 +1,238 @@
+//! Frontend assets that are embedded in the binary.
+//!
+//! Except for KaTeX, these are the same versions of the assets that aurelius serves with the live
+//! preview, so that exported documents look the same as their previews. aurelius's page loads
+//! KaTeX from a CDN, so when the `offline` setting is enabled, previews are viewed through a page
+//! of our own that only loads assets served by mdpls.
+
+/// Embeds the stylesheets of the highlight.js styles with the given names. Only stylesheets are
+/// embedded, not the images that a few styles use as backgrounds.
+macro_rules! highlight_styles {
+    ($($name:literal,)*) => {
+        &[$(($name, include_str!(concat!("../assets/highlight.js/styles/", $name, ".css"))),)*]
+    };
+}
+
+/// The highlight.js styles, named by the `codeTheme` setting.
+const HIGHLIGHT_STYLES: &[(&str, &str)] = highlight_styles![
+    "agate",
+    "androidstudio",
+    "arduino-light",
+    "arta",
+    "ascetic",
+    "atelier-cave-dark",
+    "atelier-cave-light",
+    "atelier-cave.dark",
+    "atelier-cave.light",
+    "atelier-dune-dark",
+    "atelier-dune-light",
+    "atelier-dune.dark",
+    "atelier-dune.light",
+    "atelier-estuary-dark",
+    "atelier-estuary-light",
+    "atelier-estuary.dark",
+    "atelier-estuary.light",
+    "atelier-forest-dark",
+    "atelier-forest-light",
+    "atelier-forest.dark",
+    "atelier-forest.light",
+    "atelier-heath-dark",
+    "atelier-heath-light",
+    "atelier-heath.dark",
+    "atelier-heath.light",
+    "atelier-lakeside-dark",
+    "atelier-lakeside-light",
+    "atelier-lakeside.dark",
+    "atelier-lakeside.light",
+    "atelier-plateau-dark",
+    "atelier-plateau-light",
+    "atelier-plateau.dark",
+    "atelier-plateau.light",
+    "atelier-savanna-dark",
+    "atelier-savanna-light",
+    "atelier-savanna.dark",
+    "atelier-savanna.light",
+    "atelier-seaside-dark",
+    "atelier-seaside-light",
+    "atelier-seaside.dark",
+    "atelier-seaside.light",
+    "atelier-sulphurpool-dark",
+    "atelier-sulphurpool-light",
+    "atelier-sulphurpool.dark",
+    "atelier-sulphurpool.light",
+    "atom-one-dark",
+    "atom-one-light",
+    "brown-paper",
+    "brown_paper",
+    "codepen-embed",
+    "color-brewer",
+    "darcula",
+    "dark",
+    "darkula",
+    "default",
+    "docco",
+    "dracula",
+    "far",
+    "foundation",
+    "github-gist",
+    "github",
+    "googlecode",
+    "grayscale",
+    "gruvbox-dark",
+    "gruvbox-light",
+    "hopscotch",
+    "hybrid",
+    "idea",
+    "ir-black",
+    "ir_black",
+    "kimbie.dark",
+    "kimbie.light",
+    "magula",
+    "mono-blue",
+    "monokai-sublime",
+    "monokai",
+    "monokai_sublime",
+    "obsidian",
+    "ocean",
+    "paraiso-dark",
+    "paraiso-light",
+    "paraiso.dark",
+    "paraiso.light",
+    "pojoaque",
+    "purebasic",
+    "qtcreator_dark",
+    "qtcreator_light",
+    "railscasts",
+    "rainbow",
+    "routeros",
+    "school-book",
+    "school_book",
+    "solarized-dark",
+    "solarized-light",
+    "solarized_dark",
+    "solarized_light",
+    "sunburst",
+    "tomorrow-night-blue",
+    "tomorrow-night-bright",
+    "tomorrow-night-eighties",
+    "tomorrow-night",
+    "tomorrow",
+    "vs",
+    "vs2015",
+    "xcode",
+    "xt256",
+    "zenburn",
+];
+
+/// highlight.js, which highlights code blocks when the page is loaded.
+pub const HIGHLIGHT_JS: &str = include_str!("../assets/highlight.js/highlight.pack.min.js");
+
+/// The default stylesheet for rendered markdown.
+pub const GITHUB_MARKDOWN_CSS: &str =
+    include_str!("../assets/github-markdown-css/github-markdown.css");
+
+/// Layout of the page around the rendered markdown.
+pub const PAGE_CSS: &str = include_str!("../assets/css/styles.css");
+
+/// KaTeX, which renders math.
+pub const KATEX_JS: &str = include_str!("../assets/katex/katex.min.js");
+
+/// Defines `mdplsRenderMath`, which renders the math in an element with KaTeX.
+pub const MATH_JS: &str = include_str!("../assets/js/math.js");
+
+/// The page that previews are viewed through when the `offline` setting is enabled.
+pub const PREVIEW_HTML: &str = include_str!("../assets/preview.html");
+
+/// The client of the offline preview page, which receives content from the preview server.
+pub const PREVIEW_JS: &str = include_str!("../assets/js/preview.js");
+
+/// Returns an asset that mdpls serves to preview pages, with its content type.
+pub fn served(path: &str) -> Option<(&'static str, &'static str)> {
+    let theme = path
+        .strip_prefix("highlight.js/styles/")
+        .and_then(|style| style.strip_suffix(".css"));
+
+    if let Some(theme) = theme {
+        return highlight_style(theme).map(|style| (style, "text/css"));
+    }
+
+    match path {
+        "preview.html" => Some((PREVIEW_HTML, "text/html; charset=utf-8")),
+        "preview.js" => Some((PREVIEW_JS, "application/javascript")),
+        "styles.css" => Some((PAGE_CSS, "text/css")),
+        "github-markdown.css" => Some((GITHUB_MARKDOWN_CSS, "text/css")),
+        "highlight.pack.min.js" => Some((HIGHLIGHT_JS, "application/javascript")),
+        "katex.min.js" => Some((KATEX_JS, "application/javascript")),
+        "math.js" => Some((MATH_JS, "application/javascript")),
+        _ => None,
+    }
+}
+
+/// Returns the highlight.js style for a theme, if it exists.
+pub fn highlight_style(theme: &str) -> Option<&'static str> {
+    HIGHLIGHT_STYLES
+        .iter()
+        .find(|(name, _)| *name == theme)
+        .map(|(_, style)| *style)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::io;
+    use std::path::Path;
+
+    use super::{highlight_style, served, PREVIEW_HTML};
+
+    #[test]
+    fn highlight_styles() {
+        assert!(highlight_style("github").unwrap().contains(".hljs"));
+        assert!(highlight_style("darcula").is_some());
+        assert_eq!(highlight_style("nonexistent"), None);
+        assert_eq!(highlight_style("../../css/styles"), None);
+    }
+
+    #[test]
+    fn embed_every_highlight_style() -> io::Result<()> {
+        let styles = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/highlight.js/styles");
+
+        for entry in fs::read_dir(styles)? {
+            let path = entry?.path();
+
+            if path.extension().is_some_and(|extension| extension == "css") {
+                let theme = path.file_stem().unwrap().to_str().unwrap();
+                assert!(
+                    highlight_style(theme).is_some(),
+                    "{} is not embedded",
+                    theme
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn serve_highlight_styles() {
+        assert_eq!(
+            served("highlight.js/styles/darcula.css"),
+            Some((highlight_style("darcula").unwrap(), "text/css"))
+        );
+        assert_eq!(served("highlight.js/styles/nonexistent.css"), None);
+        assert_eq!(served("highlight.js/styles/darcula"), None);
+    }
+
+    #[test]
+    fn serve_preview_page_assets() {
+        let references = ["src=\"", "href=\""].iter().flat_map(|attribute| {
+            PREVIEW_HTML.match_indices(attribute).map(move |(i, _)| {
+                let value = &PREVIEW_HTML[i + attribute.len()..];
+                &value[..value.find('"').unwrap()]
+            })
+        });
+
+        for reference in references {
+            assert!(served(reference).is_some(), "{} is not served", reference);
+        }
+    }
+}
@@ -0,0 +1,527 @@
+//! Backends that serve previews to the user.
+//!
+//! Each open document is previewed by its own backend. By default, this is an [`aurelius`] server
+//! that the user views in their browser. Projects that embed mdpls may supply their own backend
+//! with [`Server::set_preview_backend`](crate::Server::set_preview_backend).
+
+use std::collections::HashMap;
+use std::io;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use log::*;
+use lsp_types::Url;
+
+use crate::preview;
+use crate::render;
+
+pub use crate::events::PageEvents;
+pub use crate::workers::CancellationToken;
+
+/// The range of ports that stable ports are chosen from. These are the dynamic ports, which are not
+/// assigned to any service.
+const STABLE_PORTS: Range<u16> = 49152..65024;
+
+/// How many consecutive ports are tried when binding to a specific port, in case earlier ports are
+/// in use by the previews of other documents.
+const PORT_ATTEMPTS: u16 = 64;
+
+/// Shown in place of the document once it is closed, in case the browser does not allow the
+/// preview page to close itself.
+const CLOSED_NOTICE: &str = "<p><em>This document was closed.</em></p>";
+
+/// The address that a backend should serve a preview at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindAddress {
+    pub host: String,
+
+    /// The port to serve the preview on. If the port is in use, the next ports are tried. If 0,
+    /// any free port is used.
+    pub port: u16,
+}
+
+/// Returns a port that is derived from the URI of a document, so that its preview is served at the
+/// same URL each time that the editor is started, whichever documents were opened before it.
+pub fn stable_port(uri: &Url) -> u16 {
+    // The hash must not change between versions of mdpls, so the standard library's hasher
+    // cannot be used. This is 32-bit FNV-1a.
+    let hash = uri.as_str().bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+
+    let span = u32::from(STABLE_PORTS.end - STABLE_PORTS.start);
+    STABLE_PORTS.start + (hash % span) as u16
+}
+
+/// Opens a preview for the user. Opening a preview may block, so it may be called from a worker
+/// thread.
+pub type Opener = Box<dyn FnOnce() -> io::Result<()> + Send>;
+
+/// Copies a preview's URL to the clipboard. Copying may block, so it may be called from a worker
+/// thread. It should stop early if the token is cancelled.
+pub type Copier = Box<dyn FnOnce(&CancellationToken) -> io::Result<()> + Send>;
+
+/// Serves the preview of a single document.
+///
+/// The preview is stopped when the backend is dropped. Backends are dropped by the server's main
+/// loop, so dropping should not block.
+pub trait PreviewBackend {
+    /// The URL that the preview is served at.
+    fn url(&self) -> String;
+
+    /// Replaces the content of the preview with a rendered document.
+    ///
+    /// The HTML includes the document's stylesheets and template. Unless the document was rendered
+    /// by an external renderer, each top-level block has a `data-source-line` attribute containing
+    /// the zero-based line of the source that the block starts on.
+    fn send(&mut self, html: String) -> io::Result<()>;
+
+    /// Scrolls the preview to the last block that starts on or before a zero-based line of the
+    /// source.
+    fn scroll_to(&mut self, line: u64) -> io::Result<()>;
+
+    /// Tells viewers of the preview that the document was closed. The backend is dropped
+    /// afterwards.
+    fn close(&mut self) -> io::Result<()>;
+
+    /// Sets the URL that the preview's pages should request when a block is double-clicked, so that
+    /// the editor reveals the block's source. The source line of the block must be appended to the
+    /// URL. This is only set for backends that are local.
+    fn set_reveal_url(&mut self, _url: Option<String>) {}
+
+    /// Sets the channel that sends events, such as scrolling, to the preview's pages. Pages must
+    /// subscribe to the channel's URL to receive them. This is only set for backends that are
+    /// local.
+    fn set_page_events(&mut self, _events: Option<PageEvents>) {}
+
+    /// Sets the highlight.js style used for code blocks.
+    fn set_highlight_theme(&mut self, theme: String);
+
+    /// Serves static files, such as images, from a directory.
+    fn set_static_root(&mut self, root: PathBuf);
+
+    /// Replaces the default stylesheet of the page with stylesheets, when the page is loaded. If
+    /// there are no stylesheets, the default is restored.
+    fn set_stylesheets(&mut self, paths: Vec<PathBuf>) -> io::Result<()>;
+
+    /// Returns a function that opens the preview at `url`. This is usually the backend's URL, but
+    /// it may have been rewritten to be reachable by the user. If `browser` is `None`, the preview
+    /// is opened in the default browser.
+    fn opener(&self, url: String, browser: Option<&(String, Vec<String>)>) -> Opener;
+
+    /// Returns a function that copies `url` to the clipboard of the machine running mdpls. Like
+    /// [`PreviewBackend::opener`], the URL may have been rewritten.
+    fn copier(&self, url: String) -> Copier;
+
+    /// Returns true if the preview is viewed in a browser on the machine running mdpls. Only then
+    /// does mdpls listen for events from the preview's pages, such as double-clicks, so backends
+    /// that are viewed elsewhere do not cause mdpls to open any sockets.
+    fn is_local(&self) -> bool {
+        false
+    }
+}
+
+/// Serves previews over HTTP with aurelius.
+///
+/// aurelius renders everything that it is sent as markdown, and its page replaces the content with
+/// the result. Rendered HTML is wrapped so that it passes through unchanged, and scripts are sent as
+/// elements that run when they are inserted into the page.
+pub struct AureliusBackend {
+    /// Taken when the backend is dropped.
+    server: Option<aurelius::Server>,
+
+    /// The most recently sent HTML.
+    html: String,
+
+    reveal_url: Option<String>,
+    events: Option<PageEvents>,
+}
+
+impl AureliusBackend {
+    /// Starts an aurelius server.
+    pub fn bind(address: &BindAddress) -> io::Result<Self> {
+        let mut port = address.port;
+        let last_port = match port {
+            0 => 0,
+            port => port.saturating_add(PORT_ATTEMPTS - 1),
+        };
+
+        loop {
+            match aurelius::Server::bind((&*address.host, port)) {
+                Ok(server) => {
+                    return Ok(AureliusBackend {
+                        server: Some(server),
+                        html: String::new(),
+                        reveal_url: None,
+                        events: None,
+                    })
+                }
+                Err(e) if e.kind() == io::ErrorKind::AddrInUse && port < last_port => {
+                    debug!("port {} is in use", port);
+                    port += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn server(&mut self) -> &mut aurelius::Server {
+        self.server.as_mut().expect("server was stopped")
+    }
+
+    /// Sends the most recent HTML to the page, followed by a script.
+    fn publish(&mut self, script: Option<String>) -> io::Result<()> {
+        let mut html = self.html.clone();
+
+        if let Some(url) = &self.reveal_url {
+            html.push_str(&render::reveal_on_double_click(url));
+        }
+
+        if let Some(events) = &self.events {
+            html.push_str(&render::subscribe_to_events(events.url()));
+        }
+
+        if let Some(script) = script {
+            html.push_str(&script);
+        }
+
+        let block = render::as_html_block(&html);
+        self.server().send(block)
+    }
+}
+
+impl PreviewBackend for AureliusBackend {
+    fn url(&self) -> String {
+        let server = self.server.as_ref().expect("server was stopped");
+        format!("http://{}", server.addr())
+    }
+
+    fn send(&mut self, html: String) -> io::Result<()> {
+        self.html = html;
+        self.publish(None)
+    }
+
+    fn scroll_to(&mut self, line: u64) -> io::Result<()> {
+        match &self.events {
+            Some(events) => {
+                events.send("scroll", &line.to_string());
+                Ok(())
+            }
+            // Without events, the content is sent again with a script that scrolls the page.
+            // Replacing the content does not change the scroll position.
+            None => self.publish(Some(render::scroll_to_line(line))),
+        }
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        self.server().send(render::as_html_block(CLOSED_NOTICE))
+    }
+
+    fn set_reveal_url(&mut self, url: Option<String>) {
+        self.reveal_url = url;
+    }
+
+    fn set_page_events(&mut self, events: Option<PageEvents>) {
+        self.events = events;
+    }
+
+    fn set_highlight_theme(&mut self, theme: String) {
+        self.server().set_highlight_theme(theme);
+    }
+
+    fn set_static_root(&mut self, root: PathBuf) {
+        self.server().set_static_root(root);
+    }
+
+    fn set_stylesheets(&mut self, paths: Vec<PathBuf>) -> io::Result<()> {
+        let stylesheets = paths
+            .into_iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect();
+
+        self.server().set_custom_css(stylesheets)
+    }
+
+    fn opener(&self, url: String, browser: Option<&(String, Vec<String>)>) -> Opener {
+        let browser = browser.cloned();
+
+        Box::new(move || preview::open_browser(&url, browser.as_ref()))
+    }
+
+    fn copier(&self, url: String) -> Copier {
+        Box::new(move |cancel| preview::copy_to_clipboard(&url, cancel))
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+}
+
+impl Drop for AureliusBackend {
+    fn drop(&mut self) {
+        // aurelius waits for every connection to close when it is dropped, but browsers may keep
+        // idle connections open indefinitely. Stopping the server on another thread keeps the
+        // language server responsive. Connected pages are still told that the preview closed.
+        if let Some(server) = self.server.take() {
+            thread::spawn(move || drop(server));
+        }
+    }
+}
+
+/// A call made to a [`RecordingBackend`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackendEvent {
+    /// The HTML that was sent.
+    Sent(String),
+    /// The source line that the preview was scrolled to.
+    ScrolledTo(u64),
+    Closed,
+    SetRevealUrl(Option<String>),
+    /// The URL that pages subscribe to for events.
+    SetPageEvents(Option<String>),
+    SetHighlightTheme(String),
+    SetStaticRoot(PathBuf),
+    SetStylesheets(Vec<PathBuf>),
+    Opened {
+        url: String,
+        browser: Option<(String, Vec<String>)>,
+    },
+    /// The URL that was copied to the clipboard.
+    Copied(String),
+}
+
+/// The events recorded for a document, shared by its backend and the [`Recordings`].
+type Events = Arc<Mutex<Vec<BackendEvent>>>;
+
+/// A backend that records the calls made to it instead of serving the preview. Nothing is opened or
+/// copied.
+pub struct RecordingBackend {
+    url: String,
+    events: Events,
+    local: bool,
+}
+
+impl RecordingBackend {
+    fn record(&self, event: BackendEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+impl PreviewBackend for RecordingBackend {
+    fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    fn send(&mut self, html: String) -> io::Result<()> {
+        self.record(BackendEvent::Sent(html));
+        Ok(())
+    }
+
+    fn scroll_to(&mut self, line: u64) -> io::Result<()> {
+        self.record(BackendEvent::ScrolledTo(line));
+        Ok(())
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        self.record(BackendEvent::Closed);
+        Ok(())
+    }
+
+    fn set_reveal_url(&mut self, url: Option<String>) {
+        self.record(BackendEvent::SetRevealUrl(url));
+    }
+
+    fn set_page_events(&mut self, events: Option<PageEvents>) {
+        let url = events.map(|events| events.url().to_owned());
+        self.record(BackendEvent::SetPageEvents(url));
+    }
+
+    fn set_highlight_theme(&mut self, theme: String) {
+        self.record(BackendEvent::SetHighlightTheme(theme));
+    }
+
+    fn set_static_root(&mut self, root: PathBuf) {
+        self.record(BackendEvent::SetStaticRoot(root));
+    }
+
+    fn set_stylesheets(&mut self, paths: Vec<PathBuf>) -> io::Result<()> {
+        self.record(BackendEvent::SetStylesheets(paths));
+        Ok(())
+    }
+
+    fn opener(&self, url: String, browser: Option<&(String, Vec<String>)>) -> Opener {
+        let events = Arc::clone(&self.events);
+        let browser = browser.cloned();
+
+        Box::new(move || {
+            events
+                .lock()
+                .unwrap()
+                .push(BackendEvent::Opened { url, browser });
+            Ok(())
+        })
+    }
+
+    fn copier(&self, url: String) -> Copier {
+        let events = Arc::clone(&self.events);
+
+        Box::new(move |_| {
+            events.lock().unwrap().push(BackendEvent::Copied(url));
+            Ok(())
+        })
+    }
+
+    fn is_local(&self) -> bool {
+        self.local
+    }
+}
+
+/// The events recorded by the recording backends of every document. Clones share the same
+/// recordings.
+#[derive(Debug, Clone, Default)]
+pub struct Recordings {
+    documents: Arc<Mutex<HashMap<Url, Events>>>,
+    local: Arc<AtomicBool>,
+}
+
+impl Recordings {
+    /// Creates a backend for a document. If the document was previewed before, its events are
+    /// appended to the previous ones.
+    ///
+    /// The backend's URL is `http://localhost` followed by the path of the document. If `address`
+    /// has a specific port, the URL includes it.
+    pub fn backend(&self, uri: &Url, address: &BindAddress) -> RecordingBackend {
+        let events = self
+            .documents
+            .lock()
+            .unwrap()
+            .entry(uri.clone())
+            .or_default()
+            .clone();
+
+        let port = match address.port {
+            0 => String::new(),
+            port => format!(":{}", port),
+        };
+
+        RecordingBackend {
+            url: format!("http://localhost{}{}", port, uri.path()),
+            events,
+            local: self.local.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Sets whether backends that are created afterwards act as if they are viewed in a browser on
+    /// this machine, so that mdpls listens for events from their pages. By default, they do not.
+    pub fn set_local(&self, local: bool) {
+        self.local.store(local, Ordering::SeqCst);
+    }
+
+    /// Returns the events recorded for a document.
+    pub fn events(&self, uri: &Url) -> Vec<BackendEvent> {
+        match self.documents.lock().unwrap().get(uri) {
+            Some(events) => events.lock().unwrap().clone(),
+            None => vec![],
+        }
+    }
+
+    /// Returns the most recent content sent to a document's preview.
+    pub fn content(&self, uri: &Url) -> Option<String> {
+        self.events(uri)
+            .into_iter()
+            .rev()
+            .find_map(|event| match event {
+                BackendEvent::Sent(content) => Some(content),
+                _ => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::net::TcpListener;
+
+    use lsp_types::Url;
+
+    use super::{
+        stable_port, AureliusBackend, BackendEvent, BindAddress, CancellationToken, PreviewBackend,
+        Recordings, STABLE_PORTS,
+    };
+
+    #[test]
+    fn bind_next_free_port() -> io::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let port = listener.local_addr()?.port();
+
+        let backend = AureliusBackend::bind(&BindAddress {
+            host: String::from("127.0.0.1"),
+            port,
+        })?;
+
+        assert_ne!(backend.url(), format!("http://127.0.0.1:{}", port));
+
+        Ok(())
+    }
+
+    #[test]
+    fn stable_ports() {
+        let document = Url::parse("file:///home/user/project/README.md").unwrap();
+        let port = stable_port(&document);
+
+        assert!(STABLE_PORTS.contains(&port));
+        assert_eq!(
+            port,
+            stable_port(&Url::parse("file:///home/user/project/README.md").unwrap())
+        );
+        assert_ne!(
+            port,
+            stable_port(&Url::parse("file:///home/user/project/CHANGELOG.md").unwrap())
+        );
+    }
+
+    #[test]
+    fn record_events() -> io::Result<()> {
+        let recordings = Recordings::default();
+        let uri = Url::parse("file:///tmp/test.md").unwrap();
+
+        let mut backend = recordings.backend(
+            &uri,
+            &BindAddress {
+                host: String::from("localhost"),
+                port: 0,
+            },
+        );
+        backend.set_highlight_theme(String::from("github"));
+        backend.send(String::from("first"))?;
+        backend.send(String::from("second"))?;
+        backend.scroll_to(3)?;
+        backend.opener(backend.url(), None)()?;
+        backend.copier(backend.url())(&CancellationToken::default())?;
+
+        assert_eq!(
+            recordings.events(&uri),
+            vec![
+                BackendEvent::SetHighlightTheme(String::from("github")),
+                BackendEvent::Sent(String::from("first")),
+                BackendEvent::Sent(String::from("second")),
+                BackendEvent::ScrolledTo(3),
+                BackendEvent::Opened {
+                    url: format!("http://localhost{}", uri.path()),
+                    browser: None,
+                },
+                BackendEvent::Copied(format!("http://localhost{}", uri.path())),
+            ]
+        );
+        assert_eq!(recordings.content(&uri).as_deref(), Some("second"));
+
+        let other = Url::parse("file:///tmp/other.md").unwrap();
+        assert!(recordings.events(&other).is_empty());
+        assert_eq!(recordings.content(&other), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use lsp_types::{Position, TextDocumentContentChangeEvent};
+
+/// The contents of an open text document, kept in sync with the client.
+#[derive(Debug)]
+pub struct Document {
+    text: String,
+}
+
+impl Document {
+    pub fn new(text: String) -> Self {
+        Document { text }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Apply a change sent by the client.
+    ///
+    /// Changes without a range replace the entire document.
+    pub fn apply_change(&mut self, change: TextDocumentContentChangeEvent) {
+        match change.range {
+            Some(range) => {
+                let start = self.offset_at(range.start);
+                let end = self.offset_at(range.end).max(start);
+                self.text.replace_range(start..end, &change.text);
+            }
+            None => self.text = change.text,
+        }
+    }
+
+    /// Converts an LSP position into a byte offset into the text.
+    ///
+    /// Positions count characters in UTF-16 code units. Positions past the end of a line are
+    /// clamped to the end of that line, and positions past the end of the document are clamped to
+    /// the end of the document.
+    fn offset_at(&self, position: Position) -> usize {
+        let mut line = 0;
+        let mut chars = self.text.char_indices().peekable();
+
+        while line < position.line {
+            match chars.next() {
+                Some((_, '\n')) => line += 1,
+                Some((_, '\r')) => {
+                    if let Some((_, '\n')) = chars.peek() {
+                        chars.next();
+                    }
+                    line += 1;
+                }
+                Some(_) => (),
+                None => return self.text.len(),
+            }
+        }
+
+        let mut character = 0;
+
+        while let Some(&(offset, c)) = chars.peek() {
+            if character >= position.character || c == '\n' || c == '\r' {
+                return offset;
+            }
+
+            character += c.len_utf16() as u64;
+            chars.next();
+        }
+
+        self.text.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{Position, Range, TextDocumentContentChangeEvent};
+
+    use super::Document;
+
+    fn change(start: (u64, u64), end: (u64, u64), text: &str) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: Some(Range {
+                start: Position::new(start.0, start.1),
+                end: Position::new(end.0, end.1),
+            }),
+            range_length: None,
+            text: String::from(text),
+        }
+    }
+
+    #[test]
+    fn full_change() {
+        let mut document = Document::new(String::from("# Hello"));
+
+        document.apply_change(TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: String::from("# Goodbye"),
+        });
+
+        assert_eq!(document.text(), "# Goodbye");
+    }
+
+    #[test]
+    fn insert() {
+        let mut document = Document::new(String::from("# Hello\nworld"));
+
+        document.apply_change(change((1, 0), (1, 0), "wide "));
+
+        assert_eq!(document.text(), "# Hello\nwide world");
+    }
+
+    #[test]
+    fn delete_across_lines() {
+        let mut document = Document::new(String::from("one\ntwo\nthree"));
+
+        document.apply_change(change((0, 2), (2, 1), ""));
+
+        assert_eq!(document.text(), "onhree");
+    }
+
+    #[test]
+    fn crlf_line_endings() {
+        let mut document = Document::new(String::from("one\r\ntwo\rthree"));
+
+        document.apply_change(change((1, 0), (1, 3), "2"));
+        document.apply_change(change((2, 0), (2, 5), "3"));
+
+        assert_eq!(document.text(), "one\r\n2\r3");
+    }
+
+    #[test]
+    fn utf16_positions() {
+        // U+1F600 is two UTF-16 code units and four UTF-8 bytes. U+00E9 is one UTF-16 code unit
+        // and two UTF-8 bytes.
+        let mut document = Document::new(String::from("\u{1F600}\u{E9}x"));
+
+        document.apply_change(change((0, 3), (0, 4), "y"));
+
+        assert_eq!(document.text(), "\u{1F600}\u{E9}y");
+    }
+
+    #[test]
+    fn clamp_past_end_of_line() {
+        let mut document = Document::new(String::from("short\nline"));
+
+        document.apply_change(change((0, 100), (0, 100), "!"));
+
+        assert_eq!(document.text(), "short!\nline");
+    }
+
+    #[test]
+    fn clamp_past_end_of_document() {
+        let mut document = Document::new(String::from("text"));
+
+        document.apply_change(change((5, 0), (5, 0), "\nmore"));
+
+        assert_eq!(document.text(), "text\nmore");
+    }
+}
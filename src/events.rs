@@ -0,0 +1,653 @@
+//! Listener for events sent from preview pages back to the language server.
+//!
+//! The preview server only pushes content to the browser, so preview pages report events by
+//! requesting URLs from a separate HTTP listener. Requests are made by loading images, which are
+//! not subject to cross-origin restrictions. Since any page can load them, events must include a
+//! token that is only given to preview pages.
+//!
+//! Pages subscribe to events from the language server, such as scrolling, with server-sent events
+//! from the same listener. Subscriptions also require the token.
+//!
+//! The listener also serves assets that the preview server does not bundle, such as KaTeX, and a
+//! preview page that only loads bundled assets.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, prelude::*};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+
+use httparse::{Status, EMPTY_HEADER};
+use log::*;
+use lsp_types::Url;
+
+use crate::assets;
+use crate::logging;
+
+const MAX_HEADERS: usize = 32;
+
+/// How long to wait for a connection to send its request. Browsers may open connections that they
+/// never use.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long to wait for a subscribed page to accept an event, so that pages that stop reading do
+/// not block the language server.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// How long dropping the listener waits for it to stop accepting connections.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// An event that occurred in a preview page.
+#[derive(Debug, PartialEq)]
+pub enum PreviewEvent {
+    /// The user asked to reveal a line of the source in the editor.
+    Reveal { uri: Url, line: u64 },
+}
+
+/// The pages that are subscribed to the events of a document's preview.
+#[derive(Default)]
+struct Subscribers {
+    streams: Vec<TcpStream>,
+
+    /// The most recent data of each event, which is sent to pages when they subscribe.
+    last: HashMap<&'static str, String>,
+}
+
+/// The subscribers of each document that has a [`PageEvents`].
+type Channels = Arc<Mutex<HashMap<Url, Weak<Mutex<Subscribers>>>>>;
+
+/// Sends events to the pages of a document's preview, until it is dropped. Clones send to the same
+/// pages.
+#[derive(Clone)]
+pub struct PageEvents {
+    url: String,
+    subscribers: Arc<Mutex<Subscribers>>,
+}
+
+impl PageEvents {
+    /// The URL that pages should subscribe to with an `EventSource`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Sends an event to the pages that are subscribed, and to pages that subscribe later.
+    pub fn send(&self, event: &'static str, data: &str) {
+        let mut subscribers = self.subscribers.lock().unwrap();
+
+        // Pages that were closed, or that stopped reading, are unsubscribed.
+        subscribers
+            .streams
+            .retain_mut(|stream| match write_event(stream, event, data) {
+                Ok(()) => true,
+                Err(e) => {
+                    debug!("unsubscribing preview page: {}", e);
+                    false
+                }
+            });
+
+        subscribers.last.insert(event, data.to_owned());
+    }
+}
+
+/// Listens for preview events until it is dropped.
+pub struct EventListener {
+    addr: SocketAddr,
+    /// Secret that events must include.
+    token: String,
+    channels: Channels,
+    shutdown: Arc<AtomicBool>,
+    /// Disconnected once the listening thread exits.
+    exited: Receiver<()>,
+}
+
+impl EventListener {
+    /// Starts listening for events in the background, calling `handler` with each event.
+    ///
+    /// Each connection is handled on its own thread, so `handler` may be called from several
+    /// threads at once.
+    pub fn bind<F>(handler: F) -> io::Result<Self>
+    where
+        F: Fn(PreviewEvent) + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("localhost:0")?;
+        let addr = listener.local_addr()?;
+
+        info!("listening for preview events on {}", addr);
+
+        let token = random_token();
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let stopped = Arc::clone(&shutdown);
+        let handler = Arc::new(handler);
+        let event_token = token.clone();
+        let channels = Channels::default();
+        let subscriptions = Arc::clone(&channels);
+        let (exited_tx, exited) = mpsc::channel();
+
+        logging::spawn(move || {
+            let _exited = exited_tx;
+
+            for conn in listener.incoming() {
+                if stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                let conn = match conn {
+                    Ok(conn) => conn,
+                    Err(e) => {
+                        error!("could not accept preview event connection: {}", e);
+                        break;
+                    }
+                };
+
+                let handler = Arc::clone(&handler);
+                let token = event_token.clone();
+                let channels = Arc::clone(&subscriptions);
+
+                logging::spawn(move || match read_event(conn, &token, &channels) {
+                    Ok(Some(event)) => handler(event),
+                    Ok(None) => (),
+                    Err(e)
+                        if matches!(
+                            e.kind(),
+                            io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                        ) =>
+                    {
+                        debug!("preview event connection timed out")
+                    }
+                    Err(e) => warn!("could not read preview event: {}", e),
+                });
+            }
+        });
+
+        Ok(EventListener {
+            addr,
+            token,
+            channels,
+            shutdown,
+            exited,
+        })
+    }
+
+    /// Returns the URL that a preview page should request to reveal a line of `uri`. The line
+    /// number must be appended to the URL.
+    pub fn reveal_url(&self, uri: &Url) -> String {
+        let url = Url::parse_with_params(
+            &format!("http://{}/reveal", self.addr),
+            &[("token", self.token.as_str()), ("uri", uri.as_str())],
+        )
+        .expect("invalid event URL");
+
+        format!("{}&line=", url)
+    }
+
+    /// Returns a channel for sending events to the preview pages of `uri`. It replaces any previous
+    /// channel for the document.
+    pub fn page_events(&self, uri: &Url) -> PageEvents {
+        let url = Url::parse_with_params(
+            &format!("http://{}/events", self.addr),
+            &[("token", self.token.as_str()), ("uri", uri.as_str())],
+        )
+        .expect("invalid event URL");
+
+        let subscribers = Arc::default();
+
+        let mut channels = self.channels.lock().unwrap();
+        channels.retain(|_, subscribers| subscribers.strong_count() > 0);
+        channels.insert(uri.clone(), Arc::downgrade(&subscribers));
+
+        PageEvents {
+            url: url.to_string(),
+            subscribers,
+        }
+    }
+
+    /// Returns the URL that a bundled asset is served at.
+    pub fn asset_url(&self, path: &str) -> String {
+        format!("http://{}/assets/{}", self.addr, path)
+    }
+}
+
+impl Drop for EventListener {
+    fn drop(&mut self) {
+        info!("no longer listening for preview events on {}", self.addr);
+
+        // Wake the listening thread so that it notices the shutdown.
+        self.shutdown.store(true, Ordering::SeqCst);
+        let _ = TcpStream::connect_timeout(&self.addr, SHUTDOWN_TIMEOUT);
+
+        // The thread is not joined, since it never wakes if the connection failed. Connections
+        // that are still being handled are left to time out.
+        if let Err(RecvTimeoutError::Timeout) = self.exited.recv_timeout(SHUTDOWN_TIMEOUT) {
+            warn!("preview event listener on {} did not stop", self.addr);
+        }
+    }
+}
+
+/// Returns a random token that cannot be guessed by other pages.
+fn random_token() -> String {
+    // Each `RandomState` is keyed randomly, so the hashes are unpredictable without depending on a
+    // random number generator.
+    (0..2)
+        .map(|_| format!("{:016x}", RandomState::new().build_hasher().finish()))
+        .collect()
+}
+
+fn read_event(
+    mut conn: TcpStream,
+    token: &str,
+    channels: &Channels,
+) -> io::Result<Option<PreviewEvent>> {
+    conn.set_read_timeout(Some(READ_TIMEOUT))?;
+
+    let mut buf = Vec::new();
+
+    let path = loop {
+        let mut chunk = [0; 1024];
+        let n = conn.read(&mut chunk)?;
+
+        if n == 0 {
+            return Ok(None);
+        }
+
+        buf.extend_from_slice(&chunk[..n]);
+
+        let mut headers = [EMPTY_HEADER; MAX_HEADERS];
+        let mut req = httparse::Request::new(&mut headers);
+
+        match req.parse(&buf) {
+            Ok(Status::Complete(_)) => break req.path.map(String::from),
+            Ok(Status::Partial) => continue,
+            Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        }
+    };
+
+    // The preview page is passed the URL of the preview server in its query.
+    let asset = path
+        .as_deref()
+        .and_then(|path| path.split('?').next())
+        .and_then(|path| path.strip_prefix("/assets/"))
+        .and_then(assets::served);
+
+    if let Some((contents, content_type)) = asset {
+        write!(
+            conn,
+            "HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Type: {}\r\nContent-Length: {}\r\n\r\n",
+            content_type,
+            contents.len()
+        )?;
+        conn.write_all(contents.as_bytes())?;
+
+        return Ok(None);
+    }
+
+    if let Some(uri) = path
+        .as_deref()
+        .and_then(|path| parse_subscription(path, token))
+    {
+        subscribe(conn, channels, &uri)?;
+        return Ok(None);
+    }
+
+    write!(
+        conn,
+        "HTTP/1.1 204 No Content\r\nConnection: close\r\nContent-Length: 0\r\n\r\n"
+    )?;
+
+    Ok(path.and_then(|path| parse_event(&path, token)))
+}
+
+/// Parses the event requested by a path. Events without `token` are ignored.
+fn parse_event(path: &str, token: &str) -> Option<PreviewEvent> {
+    let (path, query) = parse_query(path, token)?;
+
+    match &*path {
+        "/reveal" => Some(PreviewEvent::Reveal {
+            uri: Url::parse(query.get("uri")?).ok()?,
+            line: query.get("line")?.parse().ok()?,
+        }),
+        _ => None,
+    }
+}
+
+/// Parses the document whose events a path subscribes to. Subscriptions without `token` are
+/// ignored.
+fn parse_subscription(path: &str, token: &str) -> Option<Url> {
+    match parse_query(path, token)? {
+        (path, query) if path == "/events" => Url::parse(query.get("uri")?).ok(),
+        _ => None,
+    }
+}
+
+/// Splits a requested path from its query, if the query contains `token`.
+fn parse_query(path: &str, token: &str) -> Option<(String, HashMap<String, String>)> {
+    let url = Url::parse("http://localhost").ok()?.join(path).ok()?;
+    let query = url.query_pairs().into_owned().collect::<HashMap<_, _>>();
+
+    // Other requests, such as for the page's icon, are ignored without a warning.
+    if !matches!(url.path(), "/reveal" | "/events") {
+        return None;
+    }
+
+    if query.get("token").map(String::as_str) != Some(token) {
+        warn!("ignoring preview event with an invalid token");
+        return None;
+    }
+
+    Some((url.path().to_owned(), query))
+}
+
+/// Responds to a subscription with a stream of server-sent events, which is kept open until the
+/// page is closed or the document's channel is dropped.
+fn subscribe(mut conn: TcpStream, channels: &Channels, uri: &Url) -> io::Result<()> {
+    let subscribers = channels.lock().unwrap().get(uri).and_then(Weak::upgrade);
+
+    let subscribers = match subscribers {
+        Some(subscribers) => subscribers,
+        None => {
+            return write!(
+                conn,
+                "HTTP/1.1 404 Not Found\r\nConnection: close\r\nContent-Length: 0\r\n\r\n"
+            );
+        }
+    };
+
+    conn.set_write_timeout(Some(WRITE_TIMEOUT))?;
+
+    // Preview pages are served by the preview server, so the stream must allow other origins.
+    let mut subscribers = subscribers.lock().unwrap();
+    write!(
+        conn,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nAccess-Control-Allow-Origin: *\r\n\r\n"
+    )?;
+
+    for (event, data) in &subscribers.last {
+        write_event(&mut conn, event, data)?;
+    }
+
+    subscribers.streams.push(conn);
+
+    Ok(())
+}
+
+fn write_event(stream: &mut TcpStream, event: &str, data: &str) -> io::Result<()> {
+    let mut message = format!("event: {}\n", event);
+
+    // Each line of the data is sent as a separate field, which pages join again.
+    for line in data.split('\n') {
+        message.push_str(&format!("data: {}\n", line.trim_end_matches('\r')));
+    }
+
+    message.push('\n');
+    stream.write_all(message.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::io::{prelude::*, BufReader};
+    use std::net::TcpStream;
+    use std::sync::{mpsc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use lsp_types::Url;
+
+    use crate::assets;
+
+    use super::{parse_event, parse_subscription, EventListener, PreviewEvent};
+
+    #[test]
+    fn parse_reveal() -> Result<(), Box<dyn Error>> {
+        let event = parse_event(
+            "/reveal?token=secret&uri=file%3A%2F%2F%2Ftmp%2Fa+b.md&line=3",
+            "secret",
+        );
+
+        assert_eq!(
+            event,
+            Some(PreviewEvent::Reveal {
+                uri: Url::parse("file:///tmp/a b.md")?,
+                line: 3,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_unknown_event() {
+        assert_eq!(parse_event("/favicon.ico", "secret"), None);
+        assert_eq!(parse_event("/reveal?token=secret&line=3", "secret"), None);
+    }
+
+    #[test]
+    fn parse_invalid_token() {
+        let uri = "uri=file%3A%2F%2F%2Ftmp%2Fa.md&line=3";
+
+        assert_eq!(parse_event(&format!("/reveal?{}", uri), "secret"), None);
+        assert_eq!(
+            parse_event(&format!("/reveal?token=guess&{}", uri), "secret"),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_subscriptions() -> Result<(), Box<dyn Error>> {
+        let path = "/events?token=secret&uri=file%3A%2F%2F%2Ftmp%2Fa.md";
+
+        assert_eq!(
+            parse_subscription(path, "secret"),
+            Some(Url::parse("file:///tmp/a.md")?)
+        );
+        assert_eq!(parse_subscription(path, "guess"), None);
+        assert_eq!(parse_subscription("/events?token=secret", "secret"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn receive_reveal() -> Result<(), Box<dyn Error>> {
+        let (tx, rx) = mpsc::channel();
+        let listener = EventListener::bind(move |event| tx.send(event).unwrap())?;
+
+        let uri = Url::parse("file:///tmp/test.md")?;
+        let url = Url::parse(&format!("{}12", listener.reveal_url(&uri)))?;
+
+        let mut conn = TcpStream::connect(listener.addr)?;
+        write!(
+            conn,
+            "GET {}?{} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+            url.path(),
+            url.query().unwrap()
+        )?;
+
+        let mut response = String::new();
+        conn.read_to_string(&mut response)?;
+        assert!(response.starts_with("HTTP/1.1 204"));
+
+        assert_eq!(rx.recv()?, PreviewEvent::Reveal { uri, line: 12 });
+
+        Ok(())
+    }
+
+    #[test]
+    fn idle_connection() -> Result<(), Box<dyn Error>> {
+        let (tx, rx) = mpsc::channel();
+        let listener = EventListener::bind(move |event| tx.send(event).unwrap())?;
+
+        // A connection that never sends a request does not block other connections.
+        let _idle = TcpStream::connect(listener.addr)?;
+
+        let uri = Url::parse("file:///tmp/test.md")?;
+        let url = Url::parse(&format!("{}7", listener.reveal_url(&uri)))?;
+
+        let mut conn = TcpStream::connect(listener.addr)?;
+        write!(
+            conn,
+            "GET {}?{} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+            url.path(),
+            url.query().unwrap()
+        )?;
+
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(1))?,
+            PreviewEvent::Reveal { uri, line: 7 }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_page_events() -> Result<(), Box<dyn Error>> {
+        let listener = EventListener::bind(|_| ())?;
+        let uri = Url::parse("file:///tmp/test.md")?;
+        let events = listener.page_events(&uri);
+
+        // Pages that subscribe later receive the most recent event.
+        events.send("scroll", "2");
+        events.send("scroll", "3");
+
+        let url = Url::parse(events.url())?;
+        let conn = TcpStream::connect(listener.addr)?;
+        conn.set_read_timeout(Some(Duration::from_secs(1)))?;
+        write!(
+            &conn,
+            "GET {}?{} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+            url.path(),
+            url.query().unwrap()
+        )?;
+
+        let mut lines = BufReader::new(conn).lines();
+        let mut next_line = || lines.next().unwrap();
+
+        assert!(next_line()?.starts_with("HTTP/1.1 200"));
+        let mut headers = vec![];
+        loop {
+            match next_line()? {
+                header if header.is_empty() => break,
+                header => headers.push(header),
+            }
+        }
+        assert!(headers.contains(&String::from("Content-Type: text/event-stream")));
+
+        assert_eq!(next_line()?, "event: scroll");
+        assert_eq!(next_line()?, "data: 3");
+        assert_eq!(next_line()?, "");
+
+        events.send("scroll", "5");
+        assert_eq!(next_line()?, "event: scroll");
+        assert_eq!(next_line()?, "data: 5");
+
+        Ok(())
+    }
+
+    #[test]
+    fn subscribe_to_unknown_document() -> Result<(), Box<dyn Error>> {
+        let listener = EventListener::bind(|_| ())?;
+        let events = listener.page_events(&Url::parse("file:///tmp/test.md")?);
+        let url = events.url().replace("test.md", "unknown.md");
+        let url = Url::parse(&url)?;
+
+        let mut conn = TcpStream::connect(listener.addr)?;
+        write!(
+            conn,
+            "GET {}?{} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+            url.path(),
+            url.query().unwrap()
+        )?;
+
+        let mut response = String::new();
+        conn.read_to_string(&mut response)?;
+        assert!(response.starts_with("HTTP/1.1 404"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn serve_asset() -> Result<(), Box<dyn Error>> {
+        let listener = EventListener::bind(|_| ())?;
+        let url = Url::parse(&listener.asset_url("katex.min.js"))?;
+
+        let mut conn = TcpStream::connect(listener.addr)?;
+        write!(
+            conn,
+            "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+            url.path()
+        )?;
+
+        let mut response = String::new();
+        conn.read_to_string(&mut response)?;
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.contains("Content-Type: application/javascript"));
+        assert!(response.ends_with(assets::KATEX_JS));
+
+        Ok(())
+    }
+
+    #[test]
+    fn serve_preview_page() -> Result<(), Box<dyn Error>> {
+        let listener = EventListener::bind(|_| ())?;
+
+        let mut conn = TcpStream::connect(listener.addr)?;
+        write!(
+            conn,
+            "GET /assets/preview.html?server=http%3A%2F%2Flocalhost%3A8090%2F HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        )?;
+
+        let mut response = String::new();
+        conn.read_to_string(&mut response)?;
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.contains("Content-Type: text/html"));
+        assert!(response.ends_with(assets::PREVIEW_HTML));
+
+        Ok(())
+    }
+
+    #[test]
+    fn stop_listening() -> Result<(), Box<dyn Error>> {
+        let listener = EventListener::bind(|_| ())?;
+        let addr = listener.addr;
+
+        drop(listener);
+
+        assert!(TcpStream::connect(addr).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn stop_with_open_connections() -> Result<(), Box<dyn Error>> {
+        let (blocked_tx, blocked_rx) = mpsc::channel();
+        let (_unblock_tx, unblock_rx) = mpsc::channel::<()>();
+        let unblock_rx = Mutex::new(unblock_rx);
+
+        // The handler blocks until the test ends.
+        let listener = EventListener::bind(move |_| {
+            blocked_tx.send(()).unwrap();
+            let _ = unblock_rx.lock().unwrap().recv();
+        })?;
+
+        let _idle = TcpStream::connect(listener.addr)?;
+
+        let uri = Url::parse("file:///tmp/test.md")?;
+        let url = Url::parse(&format!("{}1", listener.reveal_url(&uri)))?;
+
+        let mut conn = TcpStream::connect(listener.addr)?;
+        write!(
+            conn,
+            "GET {}?{} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+            url.path(),
+            url.query().unwrap()
+        )?;
+        blocked_rx.recv_timeout(Duration::from_secs(1))?;
+
+        let start = Instant::now();
+        drop(listener);
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        Ok(())
+    }
+}
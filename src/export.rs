@@ -0,0 +1,519 @@
+//! Export of documents to files that can be viewed without mdpls.
+
+use std::borrow::Cow;
+use std::fs;
+use std::io::{self, prelude::*};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread::{self, JoinHandle};
+
+use log::*;
+use lsp_types::Url;
+
+use crate::assets;
+use crate::page::{self, Page};
+use crate::progress::Progress;
+use crate::render;
+use crate::settings::{ExportInput, Exporter};
+use crate::workers::CancellationToken;
+
+/// A document to export, with the settings that affect how it is rendered.
+pub struct Source {
+    pub markdown: String,
+    pub title: String,
+    pub theme: String,
+    pub renderer: Option<(String, Vec<String>)>,
+    pub page: Page,
+
+    /// The directory containing the document, if it is a local file.
+    pub dir: Option<PathBuf>,
+}
+
+impl Source {
+    /// Renders the document as a standalone HTML page.
+    ///
+    /// The page is rendered the same way as the preview, by the external renderer if it is set.
+    /// Styles and scripts are embedded in the page, and images with local paths are inlined.
+    pub fn standalone_html(&self, cancel: &CancellationToken) -> io::Result<String> {
+        let highlight_style = assets::highlight_style(&self.theme).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unknown code theme: {}", self.theme),
+            )
+        })?;
+
+        let content = match &self.renderer {
+            Some(renderer) => render::render_external(renderer, &self.markdown, cancel)?,
+            None => render::render(&self.markdown),
+        };
+
+        let content = match &self.dir {
+            Some(dir) => inline_images(&content, dir),
+            None => content,
+        };
+
+        let mut script = format!(
+            "<script>\n{}\nhljs.initHighlighting();\n</script>\n",
+            inline_script(assets::HIGHLIGHT_JS)
+        );
+
+        // KaTeX is large, so it is only included if the document might contain math.
+        if ["$", "\\(", "\\["]
+            .iter()
+            .any(|delimiter| content.contains(delimiter))
+        {
+            script.push_str(&format!(
+                "<script>\n{}\n{}\nmdplsRenderMath(document.querySelector('.markdown-body'));\n</script>\n",
+                inline_script(assets::KATEX_JS),
+                inline_script(assets::MATH_JS)
+            ));
+        }
+
+        if self.page.template.is_some() {
+            let content = format!(
+                "<style>\n{}</style>\n{}{}{}",
+                highlight_style,
+                self.page.style_elements(),
+                content,
+                script
+            );
+
+            return Ok(self.page.apply_template(&self.title, &content));
+        }
+
+        let mut html = String::with_capacity(content.len() + script.len() + 32 * 1024);
+
+        html.push_str("<!doctype html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!(
+            "<title>{}</title>\n",
+            page::escape_html(&self.title)
+        ));
+
+        // Custom stylesheets replace the default stylesheet, as they do in the preview.
+        if self.page.stylesheets.is_empty() {
+            html.push_str(&format!(
+                "<style>\n{}</style>\n",
+                assets::GITHUB_MARKDOWN_CSS
+            ));
+        }
+
+        for stylesheet in &[highlight_style, assets::PAGE_CSS] {
+            html.push_str(&format!("<style>\n{}</style>\n", stylesheet));
+        }
+
+        html.push_str(&self.page.style_elements());
+        html.push_str("</head>\n<body>\n<article class=\"markdown-body\">\n");
+        html.push_str(&content);
+        html.push_str("</article>\n");
+        html.push_str(&script);
+        html.push_str("</body>\n</html>\n");
+
+        Ok(html)
+    }
+
+    /// Resolves a path relative to the document's directory.
+    pub fn resolve(&self, path: PathBuf) -> PathBuf {
+        match &self.dir {
+            Some(dir) => dir.join(path),
+            None => path,
+        }
+    }
+}
+
+/// Exports a document to `output` with an exporter. If the export is cancelled, the exporter is
+/// killed and nothing is written.
+pub fn export(
+    source: &Source,
+    exporter: &Exporter,
+    output: &Path,
+    progress: &Progress,
+    cancel: &CancellationToken,
+) -> io::Result<()> {
+    let input = match exporter.input {
+        ExportInput::Html => {
+            progress.report(String::from("Rendering"));
+            source.standalone_html(cancel)?.into_bytes()
+        }
+        ExportInput::Markdown => source.markdown.clone().into_bytes(),
+    };
+
+    if cancel.is_cancelled() {
+        return Err(cancel.error());
+    }
+
+    let (program, args) = &exporter.command;
+    let output_arg = output.to_string_lossy();
+    let writes_output = args.iter().any(|arg| arg.contains("{output}"));
+
+    let mut command = Command::new(program);
+    command.args(args.iter().map(|arg| arg.replace("{output}", &output_arg)));
+
+    // Relative paths in the document are relative to its directory.
+    if let Some(dir) = &source.dir {
+        command.current_dir(dir);
+    }
+
+    progress.report(format!("Running {}", program));
+    info!("exporting with {:?}", command);
+
+    let stdout = pipe(&mut command, &input, cancel)?;
+
+    // The export may have been cancelled after the exporter finished.
+    if cancel.is_cancelled() {
+        return Err(cancel.error());
+    }
+
+    if !writes_output {
+        fs::write(output, stdout)?;
+    }
+
+    Ok(())
+}
+
+/// Runs a command with `input` on stdin, returning its stdout. Fails if the command exits
+/// unsuccessfully, or if it is cancelled. A cancelled command is killed.
+pub fn pipe(
+    command: &mut Command,
+    input: &[u8],
+    cancel: &CancellationToken,
+) -> io::Result<Vec<u8>> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Write and read on other threads, so that the command cannot block on a full pipe while we
+    // wait for it to exit.
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_owned();
+    let writer = thread::spawn(move || stdin.write_all(&input));
+
+    let stdout = read_to_end(child.stdout.take().unwrap());
+    let stderr = read_to_end(child.stderr.take().unwrap());
+
+    // If the command is cancelled, the readers are not joined, since processes that it started may
+    // still have the pipes open.
+    let status = cancel.wait(&mut child)?;
+
+    match writer.join() {
+        Ok(Ok(())) => (),
+        // Commands may exit without reading all of their input.
+        Ok(Err(e)) if e.kind() == io::ErrorKind::BrokenPipe => (),
+        Ok(Err(e)) => return Err(e),
+        Err(_) => return Err(io::Error::other("could not write to command")),
+    }
+
+    let read_error = |_| io::Error::other("could not read from command");
+    let stdout = stdout.join().map_err(read_error)??;
+    let stderr = stderr.join().map_err(read_error)??;
+
+    if !status.success() {
+        let stderr = String::from_utf8_lossy(&stderr);
+
+        return Err(io::Error::other(format!(
+            "{:?} failed with {}: {}",
+            command.get_program(),
+            status,
+            stderr.trim()
+        )));
+    }
+
+    Ok(stdout)
+}
+
+/// Reads a pipe to the end on another thread.
+fn read_to_end(mut pipe: impl Read + Send + 'static) -> JoinHandle<io::Result<Vec<u8>>> {
+    thread::spawn(move || {
+        let mut buf = vec![];
+        pipe.read_to_end(&mut buf)?;
+        Ok(buf)
+    })
+}
+
+/// Prepares a script to be the content of a `<script>` element, which it must not close early.
+fn inline_script(script: &str) -> String {
+    script.replace("</script", "<\\/script")
+}
+
+/// Replaces the sources of images that refer to local files with data URLs.
+fn inline_images(html: &str, base_dir: &Path) -> String {
+    let mut inlined = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<img") {
+        let end = rest[start..]
+            .find('>')
+            .map_or(rest.len(), |end| start + end);
+
+        inlined.push_str(&rest[..start]);
+        inlined.push_str(&inline_image(&rest[start..end], base_dir));
+        rest = &rest[end..];
+    }
+
+    inlined.push_str(rest);
+
+    inlined
+}
+
+/// Inlines the image of a single `<img>` tag, which does not include the closing `>`.
+fn inline_image<'a>(tag: &'a str, base_dir: &Path) -> Cow<'a, str> {
+    let src = match tag.find(" src=\"") {
+        Some(i) => i + " src=\"".len(),
+        None => return Cow::Borrowed(tag),
+    };
+
+    let src_end = match tag[src..].find('"') {
+        Some(i) => src + i,
+        None => return Cow::Borrowed(tag),
+    };
+
+    let path = match image_path(&tag[src..src_end], base_dir) {
+        Some(path) => path,
+        None => return Cow::Borrowed(tag),
+    };
+
+    let data = match fs::read(&path) {
+        Ok(data) => data,
+        Err(e) => {
+            warn!("could not inline image {}: {}", path.display(), e);
+            return Cow::Borrowed(tag);
+        }
+    };
+
+    let mime_type = match path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("svg") => "image/svg+xml",
+        Some("webp") => "image/webp",
+        Some("bmp") => "image/bmp",
+        Some("ico") => "image/x-icon",
+        _ => {
+            warn!("not inlining image of unknown type: {}", path.display());
+            return Cow::Borrowed(tag);
+        }
+    };
+
+    Cow::Owned(format!(
+        "{}data:{};base64,{}{}",
+        &tag[..src],
+        mime_type,
+        base64::encode(&data),
+        &tag[src_end..]
+    ))
+}
+
+/// Returns the local file that an image source refers to. Sources that are not file paths or
+/// `file:` URLs are not local.
+fn image_path(src: &str, base_dir: &Path) -> Option<PathBuf> {
+    let base = Url::from_directory_path(base_dir).ok()?;
+    let url = base.join(&src.replace("&amp;", "&")).ok()?;
+
+    if url.scheme() != "file" {
+        return None;
+    }
+
+    url.to_file_path().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::error::Error;
+    use std::fs;
+    use std::io;
+    use std::process::Command;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use crate::assets;
+    use crate::page::Page;
+    use crate::progress::Progress;
+    use crate::settings::{ExportInput, Exporter};
+    use crate::workers::CancellationToken;
+
+    use super::{export, inline_images, pipe, Source};
+
+    fn source(markdown: &str) -> Source {
+        Source {
+            markdown: String::from(markdown),
+            title: String::from("a & b"),
+            theme: String::from("darcula"),
+            renderer: None,
+            page: Page::default(),
+            dir: None,
+        }
+    }
+
+    #[test]
+    fn export_builtin() -> Result<(), Box<dyn Error>> {
+        let html = source("# Title\n\n```rust\nfn main() {}\n```")
+            .standalone_html(&CancellationToken::default())?;
+
+        assert!(html.contains("<title>a &amp; b</title>"));
+        assert!(html.contains("Title</h1>"));
+        assert!(html.contains("<code class=\"language-rust\">"));
+        assert!(html.contains("hljs.initHighlighting();"));
+        assert!(html.contains(assets::GITHUB_MARKDOWN_CSS));
+        assert_eq!(html.matches("</script>").count(), 1);
+
+        let mut unknown_theme = source("");
+        unknown_theme.theme = String::from("nonexistent");
+        assert!(unknown_theme
+            .standalone_html(&CancellationToken::default())
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_math() -> Result<(), Box<dyn Error>> {
+        let html =
+            source("Euler: $e^{i\\pi} = -1$").standalone_html(&CancellationToken::default())?;
+
+        assert!(html.contains(assets::KATEX_JS));
+        assert!(html.contains("mdplsRenderMath(document.querySelector('.markdown-body'));"));
+        assert_eq!(html.matches("</script>").count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_custom_page() -> Result<(), Box<dyn Error>> {
+        let mut source = source("# Title");
+        source.page.stylesheets = vec![String::from(".wiki {}")];
+
+        let html = source.standalone_html(&CancellationToken::default())?;
+        assert!(html.contains(".wiki {}"));
+        assert!(!html.contains(assets::GITHUB_MARKDOWN_CSS));
+
+        source.page.template = Some(String::from(
+            "<html><title>{{title}}</title><body>{{content}}</body></html>",
+        ));
+
+        let html = source.standalone_html(&CancellationToken::default())?;
+        assert!(html.starts_with("<html><title>a &amp; b</title><body><style>"));
+        assert!(html.contains(".wiki {}"));
+        assert!(html.contains("Title</h1>"));
+        assert!(html.ends_with("</script>\n</body></html>"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_external_renderer() -> Result<(), Box<dyn Error>> {
+        if cfg!(windows) {
+            return Ok(());
+        }
+
+        let renderer = (
+            String::from("tr"),
+            vec![String::from("a-z"), String::from("A-Z")],
+        );
+        let mut source = source("shout");
+        source.renderer = Some(renderer);
+
+        let html = source.standalone_html(&CancellationToken::default())?;
+
+        assert!(html.contains("SHOUT"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn pipe_failure() {
+        if cfg!(windows) {
+            return;
+        }
+
+        let mut command = Command::new("sh");
+        command.args(["-c", "echo oops >&2; exit 3"]);
+
+        let err = pipe(&mut command, b"", &CancellationToken::default()).unwrap_err();
+        assert!(err.to_string().contains("oops"));
+    }
+
+    #[test]
+    fn cancel_pipe() {
+        if cfg!(windows) {
+            return;
+        }
+
+        let cancel = CancellationToken::default();
+        let canceller = cancel.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            canceller.cancel();
+        });
+
+        let mut command = Command::new("sleep");
+        command.arg("10");
+
+        let start = Instant::now();
+        let err = pipe(&mut command, b"", &cancel).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::Interrupted);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn cancel_export() -> Result<(), Box<dyn Error>> {
+        if cfg!(windows) {
+            return Ok(());
+        }
+
+        let output = env::temp_dir().join(format!("mdpls-cancel-{}.md", std::process::id()));
+        let exporter = Exporter {
+            command: (String::from("cat"), vec![]),
+            input: ExportInput::Markdown,
+        };
+        let (events_tx, _events_rx) = mpsc::sync_channel(1);
+        let progress = Progress::new(None, events_tx);
+
+        let cancel = CancellationToken::default();
+        cancel.cancel();
+
+        let err = export(&source("# Title"), &exporter, &output, &progress, &cancel).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::Interrupted);
+        assert!(!output.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn inline_local_images() -> Result<(), Box<dyn Error>> {
+        let dir = env::temp_dir().join(format!("mdpls-inline-{}", std::process::id()));
+        fs::create_dir_all(dir.join("img"))?;
+        fs::write(dir.join("img/a b.png"), b"png")?;
+
+        let html = inline_images(
+            concat!(
+                r#"<p><img src="img/a%20b.png" alt="local"> "#,
+                r#"<img src="https://example.com/a.png" alt="remote"> "#,
+                r#"<img src="missing.png" alt="missing"></p>"#
+            ),
+            &dir,
+        );
+
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(
+            html,
+            concat!(
+                r#"<p><img src="data:image/png;base64,cG5n" alt="local"> "#,
+                r#"<img src="https://example.com/a.png" alt="remote"> "#,
+                r#"<img src="missing.png" alt="missing"></p>"#
+            )
+        );
+
+        Ok(())
+    }
+}
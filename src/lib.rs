@@ -1,476 +1,1429 @@
+use std::collections::HashMap;
 use std::default::Default;
-use std::fmt;
+use std::fs;
 use std::io::{self, prelude::*};
-use std::process::Command;
+use std::iter;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 
 use log::*;
 use lsp_types::notification::Notification as LspNotification;
 use lsp_types::request::Request as LspRequest;
 use lsp_types::{
-    lsp_notification, lsp_request, ExecuteCommandOptions, InitializeResult, ServerCapabilities,
-    ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
-    WorkDoneProgressOptions,
+    lsp_notification, lsp_request, ClientCapabilities, ConfigurationItem, ConfigurationParams,
+    ExecuteCommandOptions, InitializeResult, LogMessageParams, MessageType, Position,
+    ProgressToken, Range, ServerCapabilities, ServerInfo, ShowMessageParams,
+    TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions, TraceOption, Url,
+    WorkDoneProgressOptions, WorkspaceCapability, WorkspaceFolder, WorkspaceFolderCapability,
+    WorkspaceFolderCapabilityChangeNotifications,
 };
-use serde::de::{self, Visitor};
-use serde::{Deserialize, Deserializer};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 const OPEN_PREVIEW_COMMAND: &str = "Open Preview";
-
+const CLOSE_PREVIEW_COMMAND: &str = "Close Preview";
+const PREVIEW_URL_COMMAND: &str = "Preview URL";
+const COPY_PREVIEW_URL_COMMAND: &str = "Copy Preview URL";
+const EXPORT_HTML_COMMAND: &str = "Export HTML";
+const EXPORT_COMMAND: &str = "Export";
+
+/// The configuration section that contains mdpls settings.
+const CONFIGURATION_SECTION: &str = "markdown.preview";
+
+pub mod backend;
+pub mod logging;
+pub mod lsp_ext;
 pub mod protocol;
-
+pub mod recording;
+pub mod testing;
+
+mod assets;
+mod document;
+mod events;
+mod export;
+mod page;
+mod preview;
+mod progress;
+mod render;
+mod settings;
+mod watchdog;
+mod watcher;
+mod workers;
+
+use backend::{AureliusBackend, BindAddress, PreviewBackend};
+use document::Document;
+use events::{EventListener, PreviewEvent};
+use logging::LogSink;
+use lsp_ext::{LogTraceParams, ShowDocumentParams, ShowDocumentResult};
+use page::Page;
+use preview::Preview;
+use progress::Progress;
 use protocol::{
-    LspTransport, Message, Notification, ProtocolError, Request, Response, ResponseError, ResultExt,
+    error_codes, parse_params, LspReader, LspTransport, LspWriter, Message, Notification,
+    OutgoingRequests, ProtocolError, Request, Response, ResponseError, ResultExt,
 };
+use recording::Recorder;
+use settings::{Configuration, Settings};
+use watcher::FileWatcher;
+use workers::{CancellationToken, Workers};
 
-#[derive(Debug, PartialEq, Eq)]
-struct Settings {
-    /// Auto-open the preview.
-    auto: bool,
+/// Number of threads that handle slow requests in the background.
+const WORKER_THREADS: usize = 4;
 
-    /// Program and arguments to use to open the preview. If `None`, use the default browser.
-    browser: Option<(String, Vec<String>)>,
+/// Input to the server's main loop.
+pub(crate) enum Event {
+    /// A message decoded from the client, or an error decoding it.
+    Message(Result<Option<Message>, ProtocolError>),
 
-    /// highlight.js style to use for syntax highlighting in code blocks.
-    theme: String,
+    /// An event that occurred in a preview page.
+    Preview(PreviewEvent),
 
-    /// Serve static files like images. This should only be use with trusted documents.
-    serve_static: bool,
+    /// A worker finished handling a request.
+    Completed(Response),
 
-    /// Program and arguments to use to render the markdown. If `None`, use the default renderer.
-    renderer: Option<(String, Vec<String>)>,
-}
+    /// A worker sent a notification to the client.
+    Notification(Notification),
 
-impl Default for Settings {
-    fn default() -> Settings {
-        Settings {
-            auto: true,
-            browser: None,
-            theme: String::from("github"),
-            serve_static: false,
-            renderer: None,
-        }
-    }
-}
+    /// The client's process exited.
+    ClientExited,
 
-impl<'de> Deserialize<'de> for Settings {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        struct Settings {
-            markdown: Option<Markdown>,
-        }
+    /// Log records were queued to be forwarded to the client.
+    LogsQueued,
 
-        #[derive(Deserialize)]
-        struct Markdown {
-            preview: Option<Preview>,
-        }
+    /// Stylesheets or templates used by previews have changed.
+    FilesChanged,
+}
 
-        #[derive(Deserialize)]
-        #[serde(rename_all = "camelCase")]
-        struct Preview {
-            auto: Option<bool>,
-            #[serde(deserialize_with = "deserialize_opt_command")]
-            #[serde(default)]
-            browser: Option<(String, Vec<String>)>,
-            code_theme: Option<String>,
-            serve_static: Option<bool>,
-            #[serde(deserialize_with = "deserialize_opt_command")]
-            #[serde(default)]
-            renderer: Option<(String, Vec<String>)>,
-        }
+/// The successful result of dispatching a request.
+enum Reply {
+    /// The request was handled immediately.
+    Now(Value),
 
-        Settings::deserialize(deserializer).map(|settings| {
-            let preview_settings = settings.markdown.and_then(|markdown| markdown.preview);
+    /// The request must be finished by a worker.
+    Later(RequestJob),
+}
 
-            let mut settings = crate::Settings::default();
+/// The part of handling a request that runs on a worker.
+type RequestJob =
+    Box<dyn FnOnce(&CancellationToken) -> Result<Value, ResponseError> + Send + 'static>;
 
-            if let Some(preview_settings) = preview_settings {
-                if let Some(auto) = preview_settings.auto {
-                    settings.auto = auto;
-                }
+/// The server's progress through the lifecycle defined by the protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Waiting for the `initialize` request.
+    Uninitialized,
 
-                if let Some(theme) = preview_settings.code_theme {
-                    settings.theme = theme;
-                }
+    /// Handling requests and notifications normally.
+    Initialized,
 
-                settings.browser = preview_settings.browser;
+    /// The `shutdown` request was received. Waiting for the `exit` notification.
+    ShutDown,
+}
 
-                if let Some(serve_static) = preview_settings.serve_static {
-                    settings.serve_static = serve_static;
-                }
+/// Returns the title of a document: the name of its file without the extension, or its URI if it
+/// is not a file.
+fn document_title(uri: &Url) -> String {
+    uri.to_file_path()
+        .ok()
+        .as_ref()
+        .and_then(|path| path.file_stem())
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| uri.to_string())
+}
 
-                settings.renderer = preview_settings.renderer;
-            }
+/// Returns a key that identifies a request by its ID. Request IDs may be numbers or strings, so
+/// the key is the ID serialized as JSON.
+fn request_key(id: &Value) -> String {
+    id.to_string()
+}
 
-            settings
-        })
-    }
+/// A request from the client that is being handled by a worker.
+struct RunningRequest {
+    id: Value,
+    cancel: CancellationToken,
+
+    /// The token that the client sent to receive progress of the request, if any. Cancelling the
+    /// progress cancels the request.
+    work_done_token: Option<ProgressToken>,
 }
 
+/// Creates the backend for the preview of a document.
+type BackendFactory = Box<dyn FnMut(&Url, &BindAddress) -> io::Result<Box<dyn PreviewBackend>>>;
+
+/// Handles the client's response to a request sent by the server.
+type ResponseHandler<R, W> =
+    Box<dyn FnOnce(&mut Server<R, W>, Result<Value, ResponseError>) -> io::Result<()>>;
+
 pub struct Server<R, W> {
-    transport: LspTransport<R, W>,
-    settings: Settings,
-    shutdown: bool,
-    markdown_server: aurelius::Server,
-    /// True if the server is being run as part of a test. The preview will not be spawned.
-    #[doc(hidden)]
-    pub test: bool,
+    /// The reading half of the transport. Moved to a separate thread when the server starts.
+    reader: Option<LspReader<R>>,
+    writer: LspWriter<W>,
+    /// Requests sent to the client that are awaiting a response.
+    outgoing_requests: OutgoingRequests<ResponseHandler<R, W>>,
+    /// Sender for the main loop's events. Set when the server starts.
+    events_tx: Option<mpsc::SyncSender<Event>>,
+    workers: Workers,
+    /// Requests from the client that are being handled by workers, keyed by `request_key`.
+    running_requests: HashMap<String, RunningRequest>,
+    /// Listener for events from preview pages, if it could be started.
+    event_listener: Option<EventListener>,
+    /// Queues log records for the client while the server is running.
+    log_sink: Option<LogSink>,
+    /// Watches the files named by settings. Set when the server starts.
+    file_watcher: Option<FileWatcher>,
+    client_capabilities: ClientCapabilities,
+    /// True if the client can handle `window/showDocument`, which `lsp_types` does not support.
+    supports_show_document: bool,
+    workspace_folders: Vec<WorkspaceFolder>,
+    configuration: Configuration,
+    state: State,
+    /// The verbosity of `$/logTrace` notifications.
+    trace: TraceOption,
+    /// The contents of each open text document.
+    documents: HashMap<Url, Document>,
+    /// Live previews of each open text document.
+    previews: HashMap<Url, Preview>,
+    preview_backends: BackendFactory,
+    /// Host that previews are served on, overriding the `host` setting.
+    preview_host: Option<String>,
+    /// Port that previews are served on, overriding the `port` setting.
+    preview_port: Option<u16>,
+    /// The document that was most recently opened or changed.
+    active_document: Option<Url>,
 }
 
 impl<R, W> Server<R, W>
 where
-    R: Read,
+    R: Read + Send + 'static,
     W: Write,
 {
     pub fn new(reader: R, writer: W) -> Self {
-        let server = aurelius::Server::bind("localhost:0").unwrap();
-
-        let mut settings = Settings::default();
-
         // Act as if auto-open wsas previously set to false, so that the preview will open on the
         // first configuration change if auto is set to true.
-        settings.auto = false;
+        let configuration = Configuration {
+            global: Settings {
+                auto: false,
+                ..Settings::default()
+            },
+            ..Configuration::default()
+        };
+
+        let (reader, writer) = LspTransport::new(reader, writer).split();
 
         Server {
-            transport: LspTransport::new(reader, writer),
-            settings,
-            shutdown: false,
-            markdown_server: server,
-            test: false,
+            reader: Some(reader),
+            writer,
+            outgoing_requests: OutgoingRequests::default(),
+            events_tx: None,
+            workers: Workers::new(WORKER_THREADS),
+            running_requests: HashMap::new(),
+            event_listener: None,
+            log_sink: None,
+            file_watcher: None,
+            client_capabilities: ClientCapabilities::default(),
+            supports_show_document: false,
+            workspace_folders: vec![],
+            configuration,
+            state: State::Uninitialized,
+            trace: TraceOption::Off,
+            documents: HashMap::new(),
+            previews: HashMap::new(),
+            preview_backends: Box::new(|_, address| Ok(Box::new(AureliusBackend::bind(address)?))),
+            preview_host: None,
+            preview_port: None,
+            active_document: None,
         }
     }
 
-    pub fn serve(mut self) -> io::Result<()> {
+    /// Sets the function that creates the backend for each document's preview. By default,
+    /// previews are served by aurelius.
+    pub fn set_preview_backend<F>(&mut self, factory: F)
+    where
+        F: FnMut(&Url, &BindAddress) -> io::Result<Box<dyn PreviewBackend>> + 'static,
+    {
+        self.preview_backends = Box::new(factory);
+    }
+
+    /// Serves previews on a host or port, regardless of settings.
+    pub fn set_preview_address(&mut self, host: Option<String>, port: Option<u16>) {
+        self.preview_host = host;
+        self.preview_port = port;
+    }
+
+    /// Records every message exchanged with the client.
+    pub fn set_recorder(&mut self, recorder: Recorder) {
+        if let Some(reader) = &mut self.reader {
+            reader.set_recorder(recorder.clone());
+        }
+
+        self.writer.set_recorder(recorder);
+    }
+
+    /// Serves requests until the client sends the `exit` notification or closes the connection.
+    ///
+    /// Returns the code that the process should exit with. As required by the protocol, this is
+    /// non-zero if the client exits without shutting the server down first.
+    pub fn serve(mut self) -> io::Result<i32> {
+        // Allow a single event to be queued. This prevents the reader from running ahead of the
+        // main loop, if it is repeatedly failing to decode messages.
+        let (events_tx, events_rx) = mpsc::sync_channel(1);
+
+        self.events_tx = Some(events_tx.clone());
+
+        // Threads are started after the sink is attached, so that they inherit it.
+        self.log_sink = Some(logging::attach(
+            events_tx.clone(),
+            self.configuration.global.log_level,
+        ));
+
+        let mut reader = self.reader.take().expect("server is already running");
+        let messages_tx = events_tx.clone();
+        logging::spawn(move || loop {
+            let message = reader.decode();
+            let eof = matches!(message, Ok(None) | Err(ProtocolError::Io(_)));
+
+            if messages_tx.send(Event::Message(message)).is_err() || eof {
+                break;
+            }
+        });
+
+        let files_tx = events_tx.clone();
+        self.file_watcher = Some(FileWatcher::new(move || {
+            // The main loop may have already exited.
+            let _ = files_tx.send(Event::FilesChanged);
+        }));
+        self.watch_files();
+
+        let code = self.run(events_rx);
+        self.log_sink = None;
+
+        code
+    }
+
+    /// Starts listening for events from preview pages. Previews started before the listener do not
+    /// send it any events.
+    ///
+    /// The listener is started with the first preview whose backend is viewed on this machine.
+    fn listen_for_preview_events(&mut self) {
+        let events_tx = self.events_tx.clone().expect("server is not running");
+
+        let listener = EventListener::bind(move |event| {
+            // The main loop may have already exited.
+            let _ = events_tx.send(Event::Preview(event));
+        });
+
+        self.event_listener = match listener {
+            Ok(listener) => Some(listener),
+            Err(e) => {
+                error!("could not listen for preview events: {}", e);
+                None
+            }
+        };
+    }
+
+    fn run(&mut self, events_rx: mpsc::Receiver<Event>) -> io::Result<i32> {
         loop {
-            let message = match self.transport.decode() {
+            self.forward_logs()?;
+
+            let message = match events_rx.recv() {
+                Ok(Event::Message(message)) => message,
+                Ok(Event::Preview(event)) => {
+                    self.handle_preview_event(event)?;
+                    continue;
+                }
+                Ok(Event::Completed(res)) => {
+                    self.complete_request(res)?;
+                    continue;
+                }
+                Ok(Event::Notification(not)) => {
+                    self.send(Message::Notification(not))?;
+                    continue;
+                }
+                Ok(Event::ClientExited) => {
+                    warn!("client exited without shutting down the server");
+                    return Ok(1);
+                }
+                Ok(Event::LogsQueued) => continue,
+                Ok(Event::FilesChanged) => {
+                    for (uri, preview) in &mut self.previews {
+                        preview.apply_settings(self.configuration.for_document(uri));
+                    }
+
+                    continue;
+                }
+                Err(_) => return Ok(0),
+            };
+
+            let message = match message {
                 Ok(Some(message)) => message,
-                Ok(None) => return Ok(()),
+                Ok(None) => return Ok(0),
                 Err(ProtocolError::Io(err)) => return Err(err),
                 Err(err) => {
                     let code = match err {
                         ProtocolError::HTTP(..)
                         | ProtocolError::MissingContentLength
-                        | ProtocolError::InvalidContentLength => -32700,
-                        ProtocolError::InvalidJson(..) => -32600,
+                        | ProtocolError::InvalidContentLength => error_codes::PARSE_ERROR,
+                        ProtocolError::InvalidJson(..) => error_codes::INVALID_REQUEST,
                         ProtocolError::Io(..) => unimplemented!("I/O errors handled above"),
                     };
-                    let response = Message::error(ResponseError {
-                        code,
-                        message: err.to_string(),
-                        data: None,
-                    });
+                    let response = Message::error(ResponseError::new(code, err.to_string()));
 
-                    self.transport.encode(&response)?;
+                    self.send(response)?;
 
                     continue;
                 }
             };
 
+            self.log_trace("Received", &message)?;
+
             match message {
-                Message::Request(req) => {
-                    let res = self.handle_request(req);
-                    self.transport.encode(&Message::Response(res))?;
-                }
+                Message::Request(req) => self.handle_request(req)?,
                 Message::Notification(not)
                     if not.method.as_str() == <lsp_notification!("exit")>::METHOD =>
                 {
-                    return Ok(())
+                    return Ok(if self.state == State::ShutDown { 0 } else { 1 });
+                }
+                Message::Notification(not) if self.state != State::Initialized => {
+                    info!(
+                        "ignoring notification in state {:?}: {}",
+                        self.state, not.method
+                    )
                 }
-                Message::Notification(not) => self.handle_notification(not),
-                Message::Response(res) => unimplemented!("unhandled response: {:?}", res),
+                Message::Notification(not) => self.handle_notification(not)?,
+                Message::Response(res) => self.handle_response(res)?,
             }
         }
     }
 
-    fn handle_request(&mut self, req: Request) -> Response {
-        match req.method.as_str() {
-            <lsp_request!("initialize")>::METHOD => Ok(InitializeResult {
-                capabilities: ServerCapabilities {
-                    text_document_sync: Some(TextDocumentSyncCapability::Options(
-                        TextDocumentSyncOptions {
-                            open_close: Some(true),
-                            change: Some(TextDocumentSyncKind::Full),
-                            ..Default::default()
-                        },
-                    )),
-                    execute_command_provider: Some(ExecuteCommandOptions {
-                        commands: vec![String::from(OPEN_PREVIEW_COMMAND)],
-                        work_done_progress_options: WorkDoneProgressOptions {
-                            work_done_progress: None,
-                        },
+    /// Sends a message to the client. Any queued log records are sent first, so that they
+    /// precede the response to the request that caused them.
+    fn send(&mut self, message: Message) -> io::Result<()> {
+        self.forward_logs()?;
+        self.write(message)
+    }
+
+    fn write(&mut self, message: Message) -> io::Result<()> {
+        self.log_trace("Sending", &message)?;
+        self.writer.encode(&message)
+    }
+
+    fn send_notification<N>(&mut self, params: N::Params) -> io::Result<()>
+    where
+        N: LspNotification,
+        N::Params: Serialize,
+    {
+        self.send(Message::Notification(Notification::new::<N>(Some(params))))
+    }
+
+    /// Sends a `$/logTrace` notification summarizing a message, if tracing is enabled.
+    fn log_trace(&mut self, action: &str, message: &Message) -> io::Result<()> {
+        let summary = match message {
+            Message::Request(req) => format!("{} request '{} - ({})'.", action, req.method, req.id),
+            Message::Notification(not) => format!("{} notification '{}'.", action, not.method),
+            Message::Response(res) => format!("{} response '{}'.", action, res.id),
+        };
+
+        let verbose = match self.trace {
+            TraceOption::Off => return Ok(()),
+            TraceOption::Messages => None,
+            TraceOption::Verbose => {
+                Some(serde_json::to_string_pretty(message).expect("could not serialize message"))
+            }
+        };
+
+        // Traces are not traced themselves.
+        let not = Notification::new::<lsp_ext::LogTrace>(Some(LogTraceParams {
+            message: summary,
+            verbose,
+        }));
+        self.writer.encode(&Message::Notification(not))
+    }
+
+    /// Sends log records that were queued for forwarding to the client. Records are discarded
+    /// unless the server is initialized.
+    fn forward_logs(&mut self) -> io::Result<()> {
+        let records = match &self.log_sink {
+            Some(sink) => sink.take_records(),
+            None => vec![],
+        };
+
+        if self.state != State::Initialized {
+            return Ok(());
+        }
+
+        for (level, message) in records {
+            let typ = match level {
+                Level::Error => MessageType::Error,
+                Level::Warn => MessageType::Warning,
+                Level::Info => MessageType::Info,
+                Level::Debug | Level::Trace => MessageType::Log,
+            };
+
+            let not = Notification::new::<lsp_notification!("window/logMessage")>(Some(
+                LogMessageParams { typ, message },
+            ));
+            self.write(Message::Notification(not))?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns an error if a request is not allowed in the current lifecycle state.
+    fn check_request(&self, method: &str) -> Result<(), ResponseError> {
+        let is_initialize = method == <lsp_request!("initialize")>::METHOD;
+
+        let (code, message) = match self.state {
+            State::Uninitialized if is_initialize => return Ok(()),
+            State::Uninitialized => (
+                error_codes::SERVER_NOT_INITIALIZED,
+                "server has not been initialized",
+            ),
+            State::Initialized if is_initialize => (
+                error_codes::INVALID_REQUEST,
+                "server has already been initialized",
+            ),
+            State::Initialized => return Ok(()),
+            State::ShutDown => (error_codes::INVALID_REQUEST, "server has been shut down"),
+        };
+
+        Err(ResponseError::new(code, message))
+    }
+
+    /// Sends a request to the client. `handler` is called with the client's response when it
+    /// arrives.
+    fn send_request<T, F>(&mut self, params: T::Params, handler: F) -> io::Result<()>
+    where
+        T: LspRequest,
+        T::Params: Serialize,
+        T::Result: DeserializeOwned,
+        F: FnOnce(&mut Self, Result<T::Result, ResponseError>) -> io::Result<()> + 'static,
+    {
+        let handler: ResponseHandler<R, W> = Box::new(move |server, result| {
+            let result = result.and_then(|value| {
+                T::Result::deserialize(value).map_err(|e| {
+                    ResponseError::new(
+                        error_codes::PARSE_ERROR,
+                        format!("invalid result for {}: {}", T::METHOD, e),
+                    )
+                })
+            });
+
+            handler(server, result)
+        });
+
+        let req = self.outgoing_requests.register::<T>(Some(params), handler);
+        self.send(Message::Request(req))
+    }
+
+    fn handle_response(&mut self, res: Response) -> io::Result<()> {
+        match self.outgoing_requests.complete(&res) {
+            Some(handler) => handler(self, res.into_result()),
+            None => {
+                warn!("received response to unknown request: {}", res.id);
+                Ok(())
+            }
+        }
+    }
+
+    fn handle_preview_event(&mut self, event: PreviewEvent) -> io::Result<()> {
+        match event {
+            PreviewEvent::Reveal { uri, line } => {
+                if !self.supports_show_document {
+                    warn!("client cannot reveal {}", uri);
+                    return Ok(());
+                }
+
+                if !self.previews.contains_key(&uri) {
+                    warn!("ignoring reveal of document without a preview: {}", uri);
+                    return Ok(());
+                }
+
+                let position = Position::new(line, 0);
+                let params = ShowDocumentParams {
+                    uri: uri.clone(),
+                    external: None,
+                    take_focus: Some(true),
+                    selection: Some(Range::new(position, position)),
+                };
+
+                self.send_request::<lsp_ext::ShowDocument, _>(params, move |_, result| {
+                    match result {
+                        Ok(ShowDocumentResult { success: true }) => (),
+                        Ok(ShowDocumentResult { success: false }) => {
+                            warn!("client could not show {}", uri)
+                        }
+                        Err(e) => warn!("could not show {}: {}", uri, e.message),
+                    }
+
+                    Ok(())
+                })
+            }
+        }
+    }
+
+    fn handle_request(&mut self, req: Request) -> io::Result<()> {
+        let Request { id, method, params } = req;
+
+        let work_done_token = params
+            .as_ref()
+            .and_then(|params| params.get("workDoneToken"))
+            .and_then(|token| ProgressToken::deserialize(token).ok());
+
+        let result = self
+            .check_request(&method)
+            .and_then(|()| self.dispatch_request(&method, params));
+
+        let result = match result {
+            Ok(Reply::Now(value)) => Ok(value),
+            Ok(Reply::Later(job)) => {
+                self.spawn_request(id, job, work_done_token);
+                return Ok(());
+            }
+            Err(e) => {
+                warn!("could not handle {}: {}", method, e.message);
+                Err(e)
+            }
+        };
+
+        self.send(Message::Response(result.into_response(id)))
+    }
+
+    /// Finishes handling a request on a worker. The response is sent to the main loop.
+    fn spawn_request(
+        &mut self,
+        id: Value,
+        job: RequestJob,
+        work_done_token: Option<ProgressToken>,
+    ) {
+        let token = CancellationToken::default();
+        self.running_requests.insert(
+            request_key(&id),
+            RunningRequest {
+                id: id.clone(),
+                cancel: token.clone(),
+                work_done_token,
+            },
+        );
+
+        let events_tx = self.events_tx.clone().expect("server is not running");
+
+        self.workers.spawn(move || {
+            // The request may have been cancelled while it was queued.
+            if token.is_cancelled() {
+                return;
+            }
+
+            let res = job(&token).into_response(id);
+
+            // The main loop may have already exited.
+            let _ = events_tx.send(Event::Completed(res));
+        });
+    }
+
+    /// Sends the response to a request that was handled by a worker, unless the request was
+    /// cancelled.
+    fn complete_request(&mut self, res: Response) -> io::Result<()> {
+        if self
+            .running_requests
+            .remove(&request_key(&res.id))
+            .is_none()
+        {
+            debug!("discarding response to cancelled request: {}", res.id);
+            return Ok(());
+        }
+
+        self.send(Message::Response(res))
+    }
+
+    /// Cancels a request that is being handled by a worker. The client is sent a response
+    /// immediately, and the worker's response is discarded.
+    fn cancel_request(&mut self, id: Value) -> io::Result<()> {
+        let request = match self.running_requests.remove(&request_key(&id)) {
+            Some(request) => request,
+            None => {
+                debug!("ignoring cancellation of finished request: {}", id);
+                return Ok(());
+            }
+        };
+
+        request.cancel.cancel();
+
+        let res = Err::<Value, _>(ResponseError::new(
+            error_codes::REQUEST_CANCELLED,
+            "request was cancelled",
+        ))
+        .into_response(id);
+
+        self.send(Message::Response(res))
+    }
+
+    fn dispatch_request(
+        &mut self,
+        method: &str,
+        params: Option<Value>,
+    ) -> Result<Reply, ResponseError> {
+        match method {
+            <lsp_request!("initialize")>::METHOD => {
+                self.supports_show_document =
+                    params.as_ref().is_some_and(lsp_ext::supports_show_document);
+
+                let params: <lsp_request!("initialize") as LspRequest>::Params =
+                    parse_params(params)?;
+
+                self.state = State::Initialized;
+                self.client_capabilities = params.capabilities;
+                self.workspace_folders = params.workspace_folders.unwrap_or_default();
+                self.trace = params.trace.unwrap_or_default();
+
+                if let Some(pid) = params.process_id {
+                    let events_tx = self.events_tx.clone().expect("server is not running");
+
+                    watchdog::watch_process(pid, move || {
+                        // The main loop may have already exited.
+                        let _ = events_tx.send(Event::ClientExited);
+                    });
+                }
+
+                let result = InitializeResult {
+                    capabilities: ServerCapabilities {
+                        text_document_sync: Some(TextDocumentSyncCapability::Options(
+                            TextDocumentSyncOptions {
+                                open_close: Some(true),
+                                change: Some(TextDocumentSyncKind::Incremental),
+                                ..Default::default()
+                            },
+                        )),
+                        execute_command_provider: Some(ExecuteCommandOptions {
+                            commands: vec![
+                                String::from(OPEN_PREVIEW_COMMAND),
+                                String::from(CLOSE_PREVIEW_COMMAND),
+                                String::from(PREVIEW_URL_COMMAND),
+                                String::from(COPY_PREVIEW_URL_COMMAND),
+                                String::from(EXPORT_HTML_COMMAND),
+                                String::from(EXPORT_COMMAND),
+                            ],
+                            work_done_progress_options: WorkDoneProgressOptions {
+                                work_done_progress: Some(true),
+                            },
+                        }),
+                        workspace: Some(WorkspaceCapability {
+                            workspace_folders: Some(WorkspaceFolderCapability {
+                                supported: Some(true),
+                                change_notifications: Some(
+                                    WorkspaceFolderCapabilityChangeNotifications::Bool(true),
+                                ),
+                            }),
+                        }),
+                        ..Default::default()
+                    },
+                    server_info: Some(ServerInfo {
+                        name: String::from(env!("CARGO_PKG_NAME")),
+                        version: Some(String::from(env!("CARGO_PKG_VERSION"))),
                     }),
-                    ..Default::default()
-                },
-                server_info: Some(ServerInfo {
-                    name: String::from(env!("CARGO_PKG_NAME")),
-                    version: Some(String::from(env!("CARGO_PKG_VERSION"))),
-                }),
-            })
-            .into_response(req.id),
+                };
+
+                Ok(Reply::Now(
+                    serde_json::to_value(result).expect("could not serialize InitializeResult"),
+                ))
+            }
             <lsp_request!("workspace/executeCommand")>::METHOD => {
-                let params =
-                    <lsp_request!("workspace/executeCommand") as LspRequest>::Params::deserialize(
-                        req.params.unwrap_or(Value::Null),
-                    )
-                    .unwrap();
+                let params: <lsp_request!("workspace/executeCommand") as LspRequest>::Params =
+                    parse_params(params)?;
 
                 match &*params.command {
                     OPEN_PREVIEW_COMMAND => {
-                        if let Err(e) = self.open_preview() {
-                            return Err::<Value, _>(ResponseError {
-                                code: 1,
-                                message: format!("could not open preview: {}", e),
-                                data: None,
-                            })
-                            .into_response(req.id);
+                        let uri = self.command_document(params.arguments)?;
+
+                        // Restart the preview if it was closed.
+                        if !self.previews.contains_key(&uri) && self.documents.contains_key(&uri) {
+                            self.start_preview(&uri).map_err(|e| {
+                                ResponseError::new(
+                                    error_codes::INTERNAL_ERROR,
+                                    format!("could not start preview: {}", e),
+                                )
+                            })?;
+                        }
+
+                        let preview = self.preview(&uri)?;
+                        let settings = self.configuration.for_document(&uri);
+
+                        // Unless the preview is opened in a browser, opening it only sends a
+                        // message to the client.
+                        if settings.headless || self.supports_show_document {
+                            self.open_preview(&uri).map_err(|e| {
+                                ResponseError::new(error_codes::INTERNAL_ERROR, e.to_string())
+                            })?;
+
+                            return Ok(Reply::Now(Value::Null));
                         }
+
+                        let open = preview.opener(settings.browser.as_ref());
+
+                        Ok(Reply::Later(Box::new(move |_| {
+                            open().map_err(|e| {
+                                ResponseError::new(
+                                    error_codes::INTERNAL_ERROR,
+                                    format!("could not open preview: {}", e),
+                                )
+                            })?;
+
+                            Ok(Value::Null)
+                        })))
                     }
-                    _ => info!("unknown command: {}", params.command),
-                }
+                    CLOSE_PREVIEW_COMMAND => {
+                        let uri = self.command_document(params.arguments)?;
+                        self.preview(&uri)?;
+                        self.close_preview(&uri);
 
-                Ok(Value::Null).into_response(req.id)
+                        Ok(Reply::Now(Value::Null))
+                    }
+                    PREVIEW_URL_COMMAND => {
+                        let uri = self.command_document(params.arguments)?;
+                        Ok(Reply::Now(Value::from(self.preview(&uri)?.url())))
+                    }
+                    COPY_PREVIEW_URL_COMMAND => {
+                        let uri = self.command_document(params.arguments)?;
+                        let preview = self.preview(&uri)?;
+                        let url = preview.url();
+                        let copy = preview.copier();
+
+                        Ok(Reply::Later(Box::new(move |cancel| {
+                            copy(cancel).map_err(|e| {
+                                ResponseError::new(
+                                    error_codes::INTERNAL_ERROR,
+                                    format!("could not copy preview URL: {}", e),
+                                )
+                            })?;
+
+                            Ok(Value::from(url))
+                        })))
+                    }
+                    EXPORT_HTML_COMMAND => {
+                        let (uri, path): (Url, PathBuf) =
+                            parse_params(Some(Value::Array(params.arguments)))?;
+
+                        let source = self.export_source(&uri)?;
+                        let path = source.resolve(path);
+
+                        Ok(Reply::Later(Box::new(move |cancel| {
+                            source
+                                .standalone_html(cancel)
+                                .and_then(|html| {
+                                    // The request may have been cancelled while rendering.
+                                    if cancel.is_cancelled() {
+                                        return Err(cancel.error());
+                                    }
+
+                                    fs::write(&path, html)
+                                })
+                                .map_err(|e| {
+                                    ResponseError::new(
+                                        error_codes::INTERNAL_ERROR,
+                                        format!("could not export {}: {}", uri, e),
+                                    )
+                                })?;
+
+                            info!("exported {} to {}", uri, path.display());
+
+                            Ok(Value::Null)
+                        })))
+                    }
+                    EXPORT_COMMAND => {
+                        let mut arguments = params.arguments.into_iter();
+                        let uri: Url = parse_params(arguments.next())?;
+                        let format: String = parse_params(arguments.next())?;
+                        let path: Option<PathBuf> = parse_params(arguments.next())?;
+
+                        let exporter = self
+                            .configuration
+                            .for_document(&uri)
+                            .exporters
+                            .get(&format)
+                            .cloned()
+                            .ok_or_else(|| {
+                                ResponseError::new(
+                                    error_codes::INVALID_PARAMS,
+                                    format!("no exporter for format: {}", format),
+                                )
+                            })?;
+
+                        let source = self.export_source(&uri)?;
+
+                        // By default, the document is exported next to itself.
+                        let path = match path {
+                            Some(path) => source.resolve(path),
+                            None => uri
+                                .to_file_path()
+                                .map(|path| path.with_extension(&format))
+                                .map_err(|_| {
+                                    ResponseError::new(
+                                        error_codes::INVALID_PARAMS,
+                                        format!("an output path is required to export {}", uri),
+                                    )
+                                })?,
+                        };
+
+                        let progress = Progress::new(
+                            params.work_done_progress_params.work_done_token,
+                            self.events_tx.clone().expect("server is not running"),
+                        );
+
+                        Ok(Reply::Later(Box::new(move |cancel| {
+                            progress.begin(format!("Exporting {} to {}", source.title, format));
+
+                            if let Err(e) =
+                                export::export(&source, &exporter, &path, &progress, cancel)
+                            {
+                                progress.end(String::from(if cancel.is_cancelled() {
+                                    "Export cancelled"
+                                } else {
+                                    "Export failed"
+                                }));
+
+                                return Err(ResponseError::new(
+                                    error_codes::INTERNAL_ERROR,
+                                    format!("could not export {} to {}: {}", uri, format, e),
+                                ));
+                            }
+
+                            info!("exported {} to {}", uri, path.display());
+                            progress.end(format!("Exported to {}", path.display()));
+
+                            Ok(Value::Null)
+                        })))
+                    }
+                    command => Err(ResponseError::new(
+                        error_codes::INVALID_PARAMS,
+                        format!("unknown command: {}", command),
+                    )),
+                }
             }
             <lsp_request!("shutdown")>::METHOD => {
-                self.shutdown = true;
-                Ok(Value::Null).into_response(req.id)
-            }
-            method => {
-                info!("unsupported request method: {}", method);
-                Ok(Value::Null).into_response(req.id)
+                self.state = State::ShutDown;
+                Ok(Reply::Now(Value::Null))
             }
+            method => Err(ResponseError::new(
+                error_codes::METHOD_NOT_FOUND,
+                format!("unsupported request method: {}", method),
+            )),
         }
     }
 
-    fn handle_notification(&mut self, not: Notification) {
+    fn handle_notification(&mut self, not: Notification) -> io::Result<()> {
         match not.method.as_str() {
+            <lsp_notification!("$/cancelRequest")>::METHOD => {
+                let params: <lsp_notification!("$/cancelRequest") as LspNotification>::Params =
+                    match parse_params(not.params) {
+                        Ok(params) => params,
+                        Err(e) => return self.report_notification_error(&not.method, e),
+                    };
+
+                let id = serde_json::to_value(params.id).expect("could not serialize request ID");
+                self.cancel_request(id)?;
+            }
+            <lsp_notification!("window/workDoneProgress/cancel")>::METHOD => {
+                let params: <lsp_notification!("window/workDoneProgress/cancel") as LspNotification>::Params =
+                    match parse_params(not.params) {
+                        Ok(params) => params,
+                        Err(e) => return self.report_notification_error(&not.method, e),
+                    };
+
+                let id = self
+                    .running_requests
+                    .values()
+                    .find(|request| request.work_done_token.as_ref() == Some(&params.token))
+                    .map(|request| request.id.clone());
+
+                match id {
+                    Some(id) => self.cancel_request(id)?,
+                    None => debug!("ignoring cancellation of finished progress"),
+                }
+            }
+            <lsp_ext::SetTrace as LspNotification>::METHOD => {
+                let params: <lsp_ext::SetTrace as LspNotification>::Params =
+                    match parse_params(not.params) {
+                        Ok(params) => params,
+                        Err(e) => return self.report_notification_error(&not.method, e),
+                    };
+
+                self.trace = params.value;
+            }
+            <lsp_notification!("initialized")>::METHOD => {
+                if self.supports_configuration() {
+                    self.pull_configuration()?;
+                }
+            }
             <lsp_notification!("workspace/didChangeConfiguration")>::METHOD => {
-                let params = <lsp_notification!("workspace/didChangeConfiguration") as LspNotification>::Params::deserialize(
-                    not.params.unwrap(),
-                ).unwrap();
+                let params: <lsp_notification!("workspace/didChangeConfiguration") as LspNotification>::Params =
+                    match parse_params(not.params) {
+                        Ok(params) => params,
+                        Err(e) => return self.report_notification_error(&not.method, e),
+                    };
 
-                if let Ok(settings) = Settings::deserialize(params.settings) {
-                    info!("changed configuration: {:?}", settings);
+                // Clients that support pulling configuration may not send the settings with the
+                // notification.
+                if self.supports_configuration() {
+                    self.pull_configuration()?;
+                } else {
+                    match Settings::deserialize(params.settings) {
+                        Ok(settings) => self.set_configuration(Configuration {
+                            global: settings,
+                            ..Configuration::default()
+                        }),
+                        Err(e) => warn!("invalid configuration: {}", e),
+                    }
+                }
+            }
+            <lsp_notification!("workspace/didChangeWorkspaceFolders")>::METHOD => {
+                let params: <lsp_notification!("workspace/didChangeWorkspaceFolders") as LspNotification>::Params =
+                    match parse_params(not.params) {
+                        Ok(params) => params,
+                        Err(e) => return self.report_notification_error(&not.method, e),
+                    };
+
+                let removed = params.event.removed;
+                self.workspace_folders
+                    .retain(|folder| !removed.contains(folder));
+                self.workspace_folders.extend(params.event.added);
+
+                if self.supports_configuration() {
+                    self.pull_configuration()?;
+                }
+            }
+            <lsp_notification!("textDocument/didOpen")>::METHOD => {
+                let params: <lsp_notification!("textDocument/didOpen") as LspNotification>::Params =
+                    match parse_params(not.params) {
+                        Ok(params) => params,
+                        Err(e) => return self.report_notification_error(&not.method, e),
+                    };
 
-                    let old_auto_setting = self.settings.auto;
+                let uri = params.text_document.uri;
+                let document = Document::new(params.text_document.text);
 
-                    self.settings = settings;
+                self.documents.insert(uri.clone(), document);
+                self.active_document = Some(uri.clone());
 
-                    if self.settings.auto && !old_auto_setting {
-                        if let Err(e) = self.open_preview() {
-                            error!("could not open browser: {}", e);
-                        }
+                if let Err(e) = self.start_preview(&uri) {
+                    error!("could not start preview for {}: {}", uri, e);
+                    return Ok(());
+                }
+
+                if self.configuration.for_document(&uri).auto {
+                    if let Err(e) = self.open_preview(&uri) {
+                        error!("could not open browser: {}", e);
                     }
+                }
+            }
+            <lsp_notification!("textDocument/didChange")>::METHOD => {
+                let params: <lsp_notification!("textDocument/didChange") as LspNotification>::Params =
+                    match parse_params(not.params) {
+                        Ok(params) => params,
+                        Err(e) => return self.report_notification_error(&not.method, e),
+                    };
 
-                    self.markdown_server
-                        .set_highlight_theme(self.settings.theme.clone());
+                let uri = params.text_document.uri;
 
-                    // There is currently no way to unset the static root wihout restarting the browser
-                    if self.settings.serve_static {
-                        self.markdown_server
-                            .set_static_root(std::env::current_dir().unwrap())
+                let document = match self.documents.get_mut(&uri) {
+                    Some(document) => document,
+                    None => {
+                        warn!("received change for unopened document: {}", uri);
+                        return Ok(());
                     }
+                };
 
-                    if let Some(renderer) = &self.settings.renderer {
-                        let mut command = Command::new(&renderer.0);
-                        command.args(&renderer.1);
-                        self.markdown_server.set_external_renderer(command)
+                // Changes must be applied in the order that they were sent.
+                for change in params.content_changes {
+                    document.apply_change(change);
+                }
+
+                if let Some(preview) = self.previews.get_mut(&uri) {
+                    if let Err(e) = preview.update(document.text()) {
+                        error!("could not render {}: {}", uri, e);
                     }
                 }
-            }
-            <lsp_notification!("textDocument/didOpen")>::METHOD => {
-                let params =
-                    <lsp_notification!("textDocument/didOpen") as LspNotification>::Params::deserialize(
-                        not.params.unwrap(),
-                    )
-                    .unwrap();
 
-                self.markdown_server
-                    .send(params.text_document.text)
-                    .unwrap();
+                self.active_document = Some(uri);
             }
-            <lsp_notification!("textDocument/didChange")>::METHOD => {
-                let params =
-                    <lsp_notification!("textDocument/didChange") as LspNotification>::Params::deserialize(
-                        not.params.unwrap(),
-                    )
-                    .unwrap();
+            <lsp_notification!("textDocument/didClose")>::METHOD => {
+                let params: <lsp_notification!("textDocument/didClose") as LspNotification>::Params =
+                    match parse_params(not.params) {
+                        Ok(params) => params,
+                        Err(e) => return self.report_notification_error(&not.method, e),
+                    };
+
+                let uri = params.text_document.uri;
+
+                if self.documents.remove(&uri).is_none() {
+                    warn!("received close for unopened document: {}", uri);
+                }
+
+                self.close_preview(&uri);
+
+                if self.active_document.as_ref() == Some(&uri) {
+                    self.active_document = None;
+                }
 
-                let mut content_changes = params.content_changes;
+                if self.documents.is_empty() && self.configuration.global.stop_when_idle {
+                    info!("no documents are open");
+                    self.event_listener = None;
+                }
+            }
+            <lsp_ext::CursorMoved as LspNotification>::METHOD => {
+                let params: <lsp_ext::CursorMoved as LspNotification>::Params =
+                    match parse_params(not.params) {
+                        Ok(params) => params,
+                        Err(e) => return self.report_notification_error(&not.method, e),
+                    };
 
-                assert_eq!(content_changes.len(), 1);
+                let uri = params.text_document.uri;
 
-                self.markdown_server
-                    .send(content_changes.remove(0).text)
-                    .unwrap();
+                match self.previews.get_mut(&uri) {
+                    Some(preview) => {
+                        if let Err(e) = preview.scroll_to(params.line) {
+                            error!("could not scroll preview of {}: {}", uri, e);
+                        }
+                    }
+                    None => warn!("received cursor movement for unopened document: {}", uri),
+                }
             }
             <lsp_notification!("exit")>::METHOD => unreachable!("handled by caller"),
             method => info!("unimplemented notification method: {}", method),
         }
+
+        Ok(())
     }
 
-    fn open_preview(&mut self) -> io::Result<()> {
-        if self.test {
+    /// Reports a notification that could not be handled. Notifications cannot be answered, so the
+    /// error is sent to the client's log instead.
+    fn report_notification_error(&mut self, method: &str, e: ResponseError) -> io::Result<()> {
+        let message = format!("could not handle {}: {}", method, e.message);
+        error!("{}", message);
+
+        // Avoid sending the error twice.
+        if let Some(true) = self
+            .log_sink
+            .as_ref()
+            .map(|sink| sink.is_forwarded(Level::Error))
+        {
             return Ok(());
         }
 
-        if let Some((name, args)) = &mut self.settings.browser {
-            let mut command = Command::new(name);
-            command.args(args);
-            self.markdown_server.open_specific_browser(command)
-        } else {
-            self.markdown_server.open_browser()
-        }
+        self.send_notification::<lsp_notification!("window/logMessage")>(LogMessageParams {
+            typ: MessageType::Error,
+            message,
+        })
     }
-}
 
-fn deserialize_command<'de, D>(deserializer: D) -> Result<(String, Vec<String>), D::Error>
-where
-    D: Deserializer<'de>,
-{
-    struct CommandVisitor;
+    fn supports_configuration(&self) -> bool {
+        self.client_capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.configuration)
+            .unwrap_or(false)
+    }
 
-    impl<'de> Visitor<'de> for CommandVisitor {
-        type Value = (String, Vec<String>);
+    /// Requests the mdpls configuration section from the client, for the workspace as a whole and
+    /// for each workspace folder.
+    fn pull_configuration(&mut self) -> io::Result<()> {
+        let folders = self
+            .workspace_folders
+            .iter()
+            .map(|folder| folder.uri.clone())
+            .collect::<Vec<_>>();
+
+        let items = iter::once(None)
+            .chain(folders.iter().map(|folder| Some(folder.to_string())))
+            .map(|scope_uri| ConfigurationItem {
+                scope_uri,
+                section: Some(String::from(CONFIGURATION_SECTION)),
+            })
+            .collect();
+
+        self.send_request::<lsp_request!("workspace/configuration"), _>(
+            ConfigurationParams { items },
+            move |server, result| {
+                let mut sections = match result {
+                    Ok(sections) => sections.into_iter(),
+                    Err(e) => {
+                        warn!("could not fetch configuration: {}", e.message);
+                        return Ok(());
+                    }
+                };
 
-        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            write!(f, "string or array of strings")
-        }
+                let mut next_settings =
+                    || Settings::from_section(sections.next().unwrap_or(Value::Null));
 
-        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
-        where
-            E: de::Error,
-        {
-            Ok((String::from(value), vec![]))
-        }
+                let configuration = next_settings().and_then(|global| {
+                    let folders = folders
+                        .into_iter()
+                        .map(|folder| {
+                            let mut settings = next_settings()?;
 
-        fn visit_seq<S>(self, seq: S) -> Result<Self::Value, S::Error>
-        where
-            S: de::SeqAccess<'de>,
-        {
-            let args = <Vec<String>>::deserialize(de::value::SeqAccessDeserializer::new(seq))?;
-            let (program, args) = args
-                .split_first()
-                .ok_or_else(|| de::Error::invalid_length(0, &"at least a program name"))?;
+                            // Paths in folder settings are relative to the folder.
+                            if let Ok(dir) = folder.to_file_path() {
+                                settings.resolve_paths(&dir);
+                            }
 
-            Ok((String::from(program), args.to_vec()))
-        }
+                            Ok((folder, settings))
+                        })
+                        .collect::<Result<_, _>>()?;
+
+                    Ok(Configuration { global, folders })
+                });
+
+                match configuration {
+                    Ok(configuration) => server.set_configuration(configuration),
+                    Err(e) => warn!("invalid configuration: {}", e),
+                }
+
+                Ok(())
+            },
+        )
     }
 
-    deserializer.deserialize_any(CommandVisitor)
-}
+    fn set_configuration(&mut self, configuration: Configuration) {
+        info!("changed configuration: {:?}", configuration);
 
-fn deserialize_opt_command<'de, D>(
-    deserializer: D,
-) -> Result<Option<(String, Vec<String>)>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    // serde#723
-    #[derive(Deserialize)]
-    struct Wrapper(#[serde(deserialize_with = "deserialize_command")] (String, Vec<String>));
+        let old_configuration = mem::replace(&mut self.configuration, configuration);
 
-    let v = Option::deserialize(deserializer)?;
-    Ok(v.map(|Wrapper(command)| command))
-}
+        if let Some(sink) = &self.log_sink {
+            sink.set_level(self.configuration.global.log_level);
+        }
 
-#[cfg(test)]
-mod tests {
-    use std::error::Error;
+        // Unsetting the log file keeps logging to the previous one.
+        if let Some(path) = &self.configuration.global.log_file {
+            if old_configuration.global.log_file.as_ref() != Some(path) {
+                if let Err(e) = logging::log_to_file(path) {
+                    error!("could not log to {}: {}", path.display(), e);
+                }
+            }
+        }
 
-    use serde::Deserialize;
-    use serde_json::json;
+        self.watch_files();
+
+        for (uri, preview) in &mut self.previews {
+            preview.apply_settings(self.configuration.for_document(uri));
+        }
 
-    use super::Settings;
+        let auto_enabled = self
+            .previews
+            .keys()
+            .filter(|uri| {
+                self.configuration.for_document(uri).auto
+                    && !old_configuration.for_document(uri).auto
+            })
+            .cloned()
+            .collect::<Vec<_>>();
 
-    #[test]
-    fn deserialize_empty_settings() -> Result<(), Box<dyn Error>> {
-        let json = json!({});
+        for uri in auto_enabled {
+            if let Err(e) = self.open_preview(&uri) {
+                error!("could not open browser: {}", e);
+            }
+        }
+    }
 
-        assert_eq!(Settings::deserialize(json)?, Settings::default());
+    /// Watches the stylesheets and templates named by the configuration, so that previews can be
+    /// updated when they change.
+    fn watch_files(&self) {
+        if let Some(file_watcher) = &self.file_watcher {
+            let configuration = &self.configuration;
+            let files = iter::once(&configuration.global)
+                .chain(configuration.folders.values())
+                .flat_map(page::files)
+                .cloned();
+
+            file_watcher.watch(files);
+        }
+    }
 
-        Ok(())
+    /// Returns the document that a command applies to. This is the URI passed as the first
+    /// argument, or the active document if there are no arguments.
+    fn command_document(&self, arguments: Vec<Value>) -> Result<Url, ResponseError> {
+        match arguments.into_iter().next() {
+            Some(arg) => parse_params(Some(arg)),
+            None => self.active_document.clone().ok_or_else(|| {
+                ResponseError::new(error_codes::INVALID_PARAMS, "no document to preview")
+            }),
+        }
     }
 
-    #[test]
-    fn deserialize_empty_markdown_settings() -> Result<(), Box<dyn Error>> {
-        let json = json!({
-            "markdown": {}
-        });
+    /// Returns the preview of an open document.
+    fn preview(&self, uri: &Url) -> Result<&Preview, ResponseError> {
+        self.previews.get(uri).ok_or_else(|| {
+            let message = if self.documents.contains_key(uri) {
+                format!("preview is closed: {}", uri)
+            } else {
+                format!("document is not open: {}", uri)
+            };
 
-        assert_eq!(Settings::deserialize(json)?, Settings::default());
+            ResponseError::new(error_codes::INVALID_PARAMS, message)
+        })
+    }
 
-        Ok(())
+    /// Returns an open document and the settings that affect how it is exported.
+    fn export_source(&self, uri: &Url) -> Result<export::Source, ResponseError> {
+        let document = self.documents.get(uri).ok_or_else(|| {
+            ResponseError::new(
+                error_codes::INVALID_PARAMS,
+                format!("document is not open: {}", uri),
+            )
+        })?;
+
+        let settings = self.configuration.for_document(uri);
+
+        Ok(export::Source {
+            markdown: document.text().to_owned(),
+            title: document_title(uri),
+            theme: settings.theme.clone(),
+            renderer: settings.renderer.clone(),
+            page: Page::load(settings),
+            dir: uri
+                .to_file_path()
+                .ok()
+                .as_ref()
+                .and_then(|path| path.parent())
+                .map(Path::to_owned),
+        })
     }
 
-    #[test]
-    fn deserialize_empty_preview_settings() -> Result<(), Box<dyn Error>> {
-        let json = json!({
-            "markdown": {
-                "preview": {}
-            }
-        });
+    /// Starts serving the preview of an open document.
+    fn start_preview(&mut self, uri: &Url) -> io::Result<()> {
+        let address = self.preview_address(uri);
+        let backend = (self.preview_backends)(uri, &address)?;
 
-        assert_eq!(Settings::deserialize(json)?, Settings::default());
+        // Pages that are viewed elsewhere cannot reach the event listener.
+        let local = backend.is_local();
+        if local && self.event_listener.is_none() {
+            self.listen_for_preview_events();
+        }
 
-        Ok(())
-    }
+        let event_listener = self.event_listener.as_ref().filter(|_| local);
 
-    #[test]
-    fn deserialize_settings() -> Result<(), Box<dyn Error>> {
-        let json = json!({
-            "markdown": {
-                "preview": {
-                    "auto": false,
-                    "browser": "firefox"
-                }
+        // Revealing sources requires `window/showDocument`.
+        let reveal_url = event_listener
+            .filter(|_| self.supports_show_document)
+            .map(|listener| listener.reveal_url(uri));
+        let page_events = event_listener.map(|listener| listener.page_events(uri));
+        let offline_page_url = event_listener.map(|listener| listener.asset_url("preview.html"));
+
+        let settings = self.configuration.for_document(uri);
+        let mut preview = Preview::new(
+            backend,
+            settings,
+            document_title(uri),
+            reveal_url,
+            page_events,
+            offline_page_url,
+        );
+
+        if let Some(document) = self.documents.get(uri) {
+            if let Err(e) = preview.update(document.text()) {
+                error!("could not render {}: {}", uri, e);
             }
-        });
+        }
 
-        let settings = Settings::deserialize(json)?;
+        info!("serving preview of {} at {}", uri, preview.url());
 
-        assert_eq!(settings.auto, false);
-        assert_eq!(settings.browser, Some((String::from("firefox"), vec![])));
+        self.previews.insert(uri.clone(), preview);
 
         Ok(())
     }
 
-    #[test]
-    fn deserialize_browser_list() -> Result<(), Box<dyn Error>> {
-        let json = json!({
-            "markdown": {
-                "preview": {
-                    "browser": ["open", "-g"]
-                }
+    /// Stops serving the preview of a document. Connected browser tabs are told that the document
+    /// was closed.
+    fn close_preview(&mut self, uri: &Url) {
+        if let Some(preview) = self.previews.remove(uri) {
+            info!("closing preview of {}", uri);
+
+            if let Err(e) = preview.close() {
+                warn!("could not notify preview of {} that it closed: {}", uri, e);
             }
-        });
+        }
+    }
 
-        let settings = Settings::deserialize(json)?;
+    /// Returns the address that the preview of a document should be served at.
+    fn preview_address(&self, uri: &Url) -> BindAddress {
+        let settings = self.configuration.for_document(uri);
+
+        let port = match self.preview_port {
+            Some(port) => port,
+            None if settings.port == 0 && settings.stable_port => backend::stable_port(uri),
+            None => settings.port,
+        };
+
+        BindAddress {
+            host: self
+                .preview_host
+                .clone()
+                .unwrap_or_else(|| settings.host.clone()),
+            port,
+        }
+    }
 
-        assert_eq!(
-            settings.browser,
-            Some((String::from("open"), vec![String::from("-g")]))
-        );
+    fn open_preview(&mut self, uri: &Url) -> io::Result<()> {
+        let preview = self.previews.get(uri).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("document is not open: {}", uri),
+            )
+        })?;
 
-        Ok(())
+        let settings = self.configuration.for_document(uri);
+
+        if settings.headless {
+            return self.show_preview_url(uri);
+        }
+
+        if self.supports_show_document {
+            return self.show_preview_externally(uri);
+        }
+
+        preview.open(settings.browser.as_ref())
     }
 
-    #[test]
-    fn deserialize_theme() -> Result<(), Box<dyn Error>> {
-        let json = json!({
-            "markdown": {
-                "preview": {
-                    "codeTheme": "darcula"
+    /// Asks the client to open a document's preview, such as in its browser integration.
+    fn show_preview_externally(&mut self, uri: &Url) -> io::Result<()> {
+        let url = self.previews.get(uri).map(Preview::url).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("document is not open: {}", uri),
+            )
+        })?;
+
+        let params = ShowDocumentParams {
+            uri: Url::parse(&url).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            external: Some(true),
+            take_focus: None,
+            selection: None,
+        };
+
+        self.send_request::<lsp_ext::ShowDocument, _>(params, move |_, result| {
+            match result {
+                Ok(ShowDocumentResult { success: true }) => (),
+                Ok(ShowDocumentResult { success: false }) => {
+                    warn!("client could not open preview at {}", url)
                 }
+                Err(e) => warn!("could not open preview at {}: {}", url, e.message),
             }
-        });
-
-        let settings = Settings::deserialize(json)?;
 
-        assert_eq!(settings.theme, "darcula");
+            Ok(())
+        })
+    }
 
-        Ok(())
+    /// Sends the URL of a document's preview to the client, so that the user can open it.
+    fn show_preview_url(&mut self, uri: &Url) -> io::Result<()> {
+        let url = self.previews.get(uri).map(Preview::url).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("document is not open: {}", uri),
+            )
+        })?;
+
+        self.send_notification::<lsp_notification!("window/showMessage")>(ShowMessageParams {
+            typ: MessageType::Info,
+            message: format!("Preview of {} is available at {}", uri, url),
+        })
     }
 }
@@ -0,0 +1,383 @@
+//! Logging to stderr and to a file, and forwarding of log records to clients.
+//!
+//! Records are always written to stderr, filtered by the `RUST_LOG` environment variable. If a log
+//! file is set, records that pass that filter or are at least as severe as `Info` are appended to
+//! it. The log file is shared by every server in the process.
+//!
+//! Each running server attaches a sink, and records at or above the sink's level are queued for the
+//! server, which sends them to its client as `window/logMessage` notifications. Records belong to
+//! the server whose thread logged them. Threads that are started with `spawn`, and functions
+//! wrapped by `inherit`, belong to the same server as the thread that started them. Records from
+//! other threads, such as those of libraries, are queued for every server.
+//!
+//! Panics are logged as errors, with a backtrace.
+
+use std::cell::RefCell;
+use std::cmp;
+use std::fs::{File, OpenOptions};
+use std::io::{self, prelude::*};
+use std::mem;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex, Weak};
+use std::thread::{self, JoinHandle, ThreadId};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{info, Level, LevelFilter, Log, Metadata, Record};
+
+use crate::Event;
+
+/// Set once the logger is installed. Records are only forwarded if it is.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// The most verbose level that is written to stderr.
+static STDERR_LEVEL: AtomicUsize = AtomicUsize::new(0);
+
+/// The most verbose level that is forwarded to any server.
+static FORWARD_LEVEL: AtomicUsize = AtomicUsize::new(0);
+
+/// The sinks of the running servers.
+static SINKS: Mutex<Vec<Arc<Sink>>> = Mutex::new(Vec::new());
+
+/// The file that records are appended to, if any.
+static FILE: Mutex<Option<File>> = Mutex::new(None);
+
+/// The most verbose level that is written to the log file, regardless of `RUST_LOG`.
+const FILE_LEVEL: Level = Level::Info;
+
+/// Modules whose records are never forwarded. Forwarding a record sends a message, which these
+/// modules may log, so forwarding their records could loop forever.
+const UNFORWARDED_MODULES: &[&str] = &["mdpls::protocol", "mdpls::recording"];
+
+thread_local! {
+    /// The sink of the server that the current thread belongs to, if any.
+    static CURRENT: RefCell<Weak<Sink>> = const { RefCell::new(Weak::new()) };
+}
+
+struct Sink {
+    records: Mutex<Vec<(Level, String)>>,
+
+    /// The most verbose level that is forwarded.
+    level: AtomicUsize,
+
+    /// Wakes the main loop when a record is queued from another thread. Records queued by the
+    /// main loop itself are sent before it waits for the next event.
+    wake: mpsc::SyncSender<Event>,
+    main_thread: ThreadId,
+}
+
+impl Sink {
+    fn queue(&self, level: Level, message: String) {
+        if level > level_filter(self.level.load(Ordering::SeqCst)) {
+            return;
+        }
+
+        let first = {
+            let mut records = self.records.lock().unwrap();
+            records.push((level, message));
+            records.len() == 1
+        };
+
+        if first && thread::current().id() != self.main_thread {
+            // The main loop may have already exited.
+            let _ = self.wake.send(Event::LogsQueued);
+        }
+    }
+}
+
+/// Queues records for a running server, until it is dropped.
+pub(crate) struct LogSink(Arc<Sink>);
+
+impl LogSink {
+    /// Sets the most verbose level of records that are forwarded to the client.
+    pub(crate) fn set_level(&self, level: LevelFilter) {
+        self.0.level.store(level as usize, Ordering::SeqCst);
+        update_max_level();
+    }
+
+    /// Returns true if records at `level` from the server's threads will be forwarded to the
+    /// client.
+    pub(crate) fn is_forwarded(&self, level: Level) -> bool {
+        INSTALLED.load(Ordering::SeqCst)
+            && level <= level_filter(self.0.level.load(Ordering::SeqCst))
+    }
+
+    /// Removes and returns the queued records.
+    pub(crate) fn take_records(&self) -> Vec<(Level, String)> {
+        mem::take(&mut *self.0.records.lock().unwrap())
+    }
+}
+
+impl Drop for LogSink {
+    fn drop(&mut self) {
+        SINKS
+            .lock()
+            .unwrap()
+            .retain(|sink| !Arc::ptr_eq(sink, &self.0));
+        update_max_level();
+    }
+}
+
+struct Logger {
+    stderr: env_logger::Logger,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.stderr.enabled(metadata)
+            || metadata.level() <= forward_level()
+            || metadata.level() <= FILE_LEVEL && FILE.lock().unwrap().is_some()
+    }
+
+    fn log(&self, record: &Record) {
+        let matches = self.stderr.matches(record);
+
+        if matches {
+            self.stderr.log(record);
+        }
+
+        if matches || record.level() <= FILE_LEVEL {
+            if let Some(file) = &mut *FILE.lock().unwrap() {
+                write_record(file, record);
+            }
+        }
+
+        if record.level() > forward_level()
+            || UNFORWARDED_MODULES
+                .iter()
+                .any(|module| record.target().starts_with(module))
+        {
+            return;
+        }
+
+        let sinks = match CURRENT.with(|current| current.borrow().upgrade()) {
+            Some(sink) => vec![sink],
+            None => SINKS.lock().unwrap().clone(),
+        };
+
+        for sink in sinks {
+            sink.queue(record.level(), record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {
+        self.stderr.flush();
+    }
+}
+
+fn write_record(file: &mut File, record: &Record) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    // There is nowhere to report errors writing the log.
+    let _ = writeln!(
+        file,
+        "[{}.{:03} {:<5} {}] {}",
+        timestamp.as_secs(),
+        timestamp.subsec_millis(),
+        record.level(),
+        record.target(),
+        record.args()
+    )
+    .and_then(|()| file.flush());
+}
+
+/// Installs the logger and a panic hook that logs panics.
+pub fn init() {
+    let stderr = env_logger::Builder::from_default_env().build();
+    STDERR_LEVEL.store(stderr.filter() as usize, Ordering::SeqCst);
+
+    log::set_boxed_logger(Box::new(Logger { stderr })).expect("logger was already installed");
+    INSTALLED.store(true, Ordering::SeqCst);
+
+    log_panics::init();
+
+    update_max_level();
+}
+
+/// Appends records to a file, in addition to stderr. Any previous log file is closed.
+pub fn log_to_file(path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref();
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    *FILE.lock().unwrap() = Some(file);
+    update_max_level();
+
+    info!("logging to {}", path.display());
+
+    Ok(())
+}
+
+/// Sets the global maximum level to the most verbose level that any destination accepts, so that
+/// other records are skipped cheaply.
+fn update_max_level() {
+    let forward_level = SINKS
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|sink| level_filter(sink.level.load(Ordering::SeqCst)))
+        .max()
+        .unwrap_or(LevelFilter::Off);
+    FORWARD_LEVEL.store(forward_level as usize, Ordering::SeqCst);
+
+    if !INSTALLED.load(Ordering::SeqCst) {
+        return;
+    }
+
+    let mut level = cmp::max(
+        level_filter(STDERR_LEVEL.load(Ordering::SeqCst)),
+        forward_level,
+    );
+
+    if FILE.lock().unwrap().is_some() {
+        level = cmp::max(level, FILE_LEVEL.to_level_filter());
+    }
+
+    log::set_max_level(level);
+}
+
+/// Starts queueing records at or above `level` for a server whose main loop runs on the current
+/// thread. The current thread belongs to the server.
+pub(crate) fn attach(wake: mpsc::SyncSender<Event>, level: LevelFilter) -> LogSink {
+    let sink = Arc::new(Sink {
+        records: Mutex::new(vec![]),
+        level: AtomicUsize::new(level as usize),
+        wake,
+        main_thread: thread::current().id(),
+    });
+
+    CURRENT.with(|current| *current.borrow_mut() = Arc::downgrade(&sink));
+    SINKS.lock().unwrap().push(Arc::clone(&sink));
+    update_max_level();
+
+    LogSink(sink)
+}
+
+/// Wraps a function so that, wherever it runs, it belongs to the same server as the current
+/// thread.
+pub(crate) fn inherit<F, T>(f: F) -> impl FnOnce() -> T + Send + 'static
+where
+    F: FnOnce() -> T + Send + 'static,
+{
+    let sink = CURRENT.with(|current| current.borrow().clone());
+
+    move || {
+        let previous = CURRENT.with(|current| mem::replace(&mut *current.borrow_mut(), sink));
+        let result = f();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+
+        result
+    }
+}
+
+/// Spawns a thread that belongs to the same server as the current thread.
+pub(crate) fn spawn<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    thread::spawn(inherit(f))
+}
+
+fn forward_level() -> LevelFilter {
+    if INSTALLED.load(Ordering::SeqCst) {
+        level_filter(FORWARD_LEVEL.load(Ordering::SeqCst))
+    } else {
+        LevelFilter::Off
+    }
+}
+
+fn level_filter(level: usize) -> LevelFilter {
+    match level {
+        0 => LevelFilter::Off,
+        1 => LevelFilter::Error,
+        2 => LevelFilter::Warn,
+        3 => LevelFilter::Info,
+        4 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::error::Error;
+    use std::fs;
+    use std::process;
+    use std::sync::{mpsc, Once};
+    use std::thread;
+
+    use log::{warn, LevelFilter};
+
+    use super::{attach, init, log_to_file, spawn, FILE};
+
+    /// Installs the logger, which can only be installed once per process.
+    fn install() {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(init);
+    }
+
+    #[test]
+    fn log_panic() -> Result<(), Box<dyn Error>> {
+        let path = env::temp_dir().join(format!("mdpls-panic-{}.log", process::id()));
+
+        install();
+        log_to_file(&path)?;
+
+        let result = thread::Builder::new()
+            .name(String::from("handler"))
+            .spawn(|| panic!("could not handle notification"))?
+            .join();
+        assert!(result.is_err());
+
+        *FILE.lock().unwrap() = None;
+
+        let log = fs::read_to_string(&path)?;
+        fs::remove_file(&path)?;
+
+        assert!(log.contains("thread 'handler' panicked at 'could not handle notification'"));
+
+        // The backtrace includes the function that panicked.
+        assert!(log.contains("logging::tests::log_panic"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn forward_to_own_server() {
+        install();
+
+        let messages = |records: Vec<_>| {
+            records
+                .into_iter()
+                .map(|(_, message)| message)
+                .collect::<Vec<String>>()
+        };
+
+        let (wake, _events) = mpsc::sync_channel(16);
+        let sink = attach(wake, LevelFilter::Warn);
+
+        let other = thread::spawn(move || {
+            let (wake, _events) = mpsc::sync_channel(16);
+            let sink = attach(wake, LevelFilter::Warn);
+
+            warn!("other server");
+            spawn(|| warn!("thread of other server")).join().unwrap();
+
+            messages(sink.take_records())
+        })
+        .join()
+        .unwrap();
+
+        warn!("this server");
+        let this = messages(sink.take_records());
+
+        assert!(this.contains(&String::from("this server")));
+        assert!(!this.iter().any(|message| message.contains("other server")));
+
+        assert!(other.contains(&String::from("other server")));
+        assert!(other.contains(&String::from("thread of other server")));
+        assert!(!other.contains(&String::from("this server")));
+    }
+}
@@ -0,0 +1,107 @@
+//! mdpls-specific extensions to the language server protocol.
+
+use lsp_types::notification::Notification;
+use lsp_types::request::Request;
+use lsp_types::{Range, TextDocumentIdentifier, TraceOption, Url};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Sent from the client to the server when the cursor moves within a document, so that the preview
+/// can scroll to the matching block.
+#[derive(Debug)]
+pub enum CursorMoved {}
+
+impl Notification for CursorMoved {
+    type Params = CursorMovedParams;
+    const METHOD: &'static str = "mdpls/cursorMoved";
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CursorMovedParams {
+    pub text_document: TextDocumentIdentifier,
+
+    /// Zero-based line that the cursor is on.
+    pub line: u64,
+}
+
+/// Asks the client to display a document, optionally selecting a range within it.
+///
+/// This request is part of version 3.16 of the protocol, which is newer than the protocol
+/// supported by `lsp_types`.
+#[derive(Debug)]
+pub enum ShowDocument {}
+
+impl Request for ShowDocument {
+    type Params = ShowDocumentParams;
+    type Result = ShowDocumentResult;
+    const METHOD: &'static str = "window/showDocument";
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShowDocumentParams {
+    pub uri: Url,
+
+    /// Show the document in an external program, such as a browser.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external: Option<bool>,
+
+    /// Focus the window that the document is shown in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_focus: Option<bool>,
+
+    /// Range to select in the document, if it is a text document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection: Option<Range>,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct ShowDocumentResult {
+    pub success: bool,
+}
+
+/// Returns true if the parameters of an `initialize` request advertise support for
+/// `window/showDocument`.
+pub fn supports_show_document(params: &Value) -> bool {
+    params
+        .pointer("/capabilities/window/showDocument/support")
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+/// Sets the level of `$/logTrace` notifications that the server sends.
+///
+/// This notification is part of version 3.16 of the protocol.
+#[derive(Debug)]
+pub enum SetTrace {}
+
+impl Notification for SetTrace {
+    type Params = SetTraceParams;
+    const METHOD: &'static str = "$/setTrace";
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct SetTraceParams {
+    pub value: TraceOption,
+}
+
+/// Logs a trace of the server's execution, such as the messages that it sends and receives.
+///
+/// This notification is part of version 3.16 of the protocol.
+#[derive(Debug)]
+pub enum LogTrace {}
+
+impl Notification for LogTrace {
+    type Params = LogTraceParams;
+    const METHOD: &'static str = "$/logTrace";
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct LogTraceParams {
+    pub message: String,
+
+    /// Additional information, only sent if the trace level is verbose.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verbose: Option<String>,
+}
@@ -1,18 +1,270 @@
 use std::env;
 use std::error::Error;
-use std::io;
+use std::fmt::Display;
+use std::io::{self, prelude::*, Cursor};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
 
+use log::*;
+
+use mdpls::backend::Recordings;
+use mdpls::recording::{self, Recorder};
 use mdpls::Server;
 
+/// The stream that the server communicates with the client over.
+#[derive(Debug, PartialEq)]
+enum Transport {
+    /// stdin and stdout.
+    Stdio,
+
+    /// Listen for a TCP connection from the client on a local port.
+    Listen(u16),
+
+    /// Connect to a client that is listening on a local TCP port.
+    Socket(u16),
+
+    /// Connect to a client that is listening on a named pipe (on Windows) or a Unix domain socket.
+    Pipe(PathBuf),
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    transport: Transport,
+
+    /// Replay the client messages in a recording instead of communicating with a client. Set by
+    /// the `replay` subcommand.
+    replay: Option<PathBuf>,
+
+    /// Record the session to a file.
+    record: Option<PathBuf>,
+
+    /// Append log records to a file.
+    log_file: Option<PathBuf>,
+
+    /// Host that previews are served on.
+    preview_host: Option<String>,
+
+    /// Port that previews are served on.
+    preview_port: Option<u16>,
+
+    test: bool,
+}
+
+impl Args {
+    /// Parses command-line arguments, excluding the program name.
+    ///
+    /// Options that take a value accept it either as the next argument or after an `=`, as in
+    /// `--socket=5007`. This is how `vscode-languageclient` passes them.
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args {
+            transport: Transport::Stdio,
+            replay: None,
+            record: None,
+            log_file: None,
+            preview_host: None,
+            preview_port: None,
+            test: false,
+        };
+
+        let mut args = args.into_iter().peekable();
+
+        if args.peek().map(String::as_str) == Some("replay") {
+            args.next();
+            let path = args.next().ok_or("replay requires a recording")?;
+            parsed.replay = Some(PathBuf::from(path));
+        }
+
+        while let Some(arg) = args.next() {
+            let (flag, mut value) = match arg.find('=') {
+                Some(i) if arg.starts_with("--") => (&arg[..i], Some(String::from(&arg[i + 1..]))),
+                _ => (&*arg, None),
+            };
+
+            let mut value = |name| {
+                value
+                    .take()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("{} requires a value", name))
+            };
+
+            match flag {
+                "--stdio" => parsed.transport = Transport::Stdio,
+                "--listen" => {
+                    parsed.transport = Transport::Listen(parse_value(flag, value(flag)?)?)
+                }
+                "--socket" => {
+                    parsed.transport = Transport::Socket(parse_value(flag, value(flag)?)?)
+                }
+                "--pipe" => parsed.transport = Transport::Pipe(PathBuf::from(value(flag)?)),
+                "--record" => parsed.record = Some(PathBuf::from(value(flag)?)),
+                "--log-file" => parsed.log_file = Some(PathBuf::from(value(flag)?)),
+                "--preview-host" => parsed.preview_host = Some(value(flag)?),
+                "--preview-port" => parsed.preview_port = Some(parse_value(flag, value(flag)?)?),
+                _ if flag.contains("test") => parsed.test = true,
+                _ => warn!("ignoring unknown argument: {}", arg),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn parse_value<T>(flag: &str, value: String) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| format!("invalid value for {}: {}: {}", flag, value, e))
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
-    env_logger::init();
+    mdpls::logging::init();
+
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(2);
+        }
+    };
+
+    if let Some(path) = &args.log_file {
+        mdpls::logging::log_to_file(path)?;
+    }
+
+    if let Some(path) = &args.replay {
+        let input = recording::replay_input(&recording::read(path)?)?;
+        let stdout = io::stdout();
+
+        let code = serve(Cursor::new(input), stdout.lock(), &args)?;
+        process::exit(code);
+    }
+
+    let code = match &args.transport {
+        Transport::Stdio => {
+            let stdout = io::stdout();
+            serve(io::stdin(), stdout.lock(), &args)?
+        }
+        Transport::Listen(port) => {
+            let listener = TcpListener::bind(("127.0.0.1", *port))?;
+            info!("listening on {}", listener.local_addr()?);
+
+            let (stream, addr) = listener.accept()?;
+            info!("accepted connection from {}", addr);
+
+            serve(stream.try_clone()?, stream, &args)?
+        }
+        Transport::Socket(port) => {
+            let stream = TcpStream::connect(("127.0.0.1", *port))?;
+            serve(stream.try_clone()?, stream, &args)?
+        }
+        Transport::Pipe(path) => {
+            #[cfg(unix)]
+            let stream = std::os::unix::net::UnixStream::connect(path)?;
+
+            #[cfg(windows)]
+            let stream = std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(path)?;
+
+            serve(stream.try_clone()?, stream, &args)?
+        }
+    };
+
+    process::exit(code);
+}
+
+fn serve<R, W>(reader: R, writer: W, args: &Args) -> io::Result<i32>
+where
+    R: Read + Send + 'static,
+    W: Write,
+{
+    let mut server = Server::new(reader, writer);
+    server.set_preview_address(args.preview_host.clone(), args.preview_port);
+
+    // Replayed sessions should not open browsers.
+    if args.test || args.replay.is_some() {
+        let recordings = Recordings::default();
+        server.set_preview_backend(move |uri, address| {
+            Ok(Box::new(recordings.backend(uri, address)))
+        });
+    }
+
+    if let Some(path) = &args.record {
+        server.set_recorder(Recorder::create(path)?);
+    }
+
+    server.serve()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{Args, Transport};
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|arg| String::from(*arg)))
+    }
+
+    #[test]
+    fn parse_transport() {
+        assert_eq!(parse(&[]).unwrap().transport, Transport::Stdio);
+        assert_eq!(parse(&["--stdio"]).unwrap().transport, Transport::Stdio);
+        assert_eq!(
+            parse(&["--listen", "5007"]).unwrap().transport,
+            Transport::Listen(5007)
+        );
+        assert_eq!(
+            parse(&["--socket=5007"]).unwrap().transport,
+            Transport::Socket(5007)
+        );
+        assert_eq!(
+            parse(&["--pipe=/tmp/mdpls.sock"]).unwrap().transport,
+            Transport::Pipe(PathBuf::from("/tmp/mdpls.sock"))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_transport() {
+        assert!(parse(&["--socket"]).is_err());
+        assert!(parse(&["--listen", "not a port"]).is_err());
+    }
+
+    #[test]
+    fn parse_replay() {
+        let args = parse(&["replay", "session.jsonl", "--record", "replayed.jsonl"]).unwrap();
+
+        assert_eq!(args.replay, Some(PathBuf::from("session.jsonl")));
+        assert_eq!(args.record, Some(PathBuf::from("replayed.jsonl")));
+
+        assert!(parse(&["replay"]).is_err());
+    }
+
+    #[test]
+    fn parse_log_file() {
+        let args = parse(&["--log-file=/tmp/mdpls.log"]).unwrap();
+        assert_eq!(args.log_file, Some(PathBuf::from("/tmp/mdpls.log")));
+    }
+
+    #[test]
+    fn parse_preview_address() {
+        let args = parse(&["--preview-host", "0.0.0.0", "--preview-port=8090"]).unwrap();
 
-    let stdin = io::stdin();
-    let stdout = io::stdout();
+        assert_eq!(args.preview_host.as_deref(), Some("0.0.0.0"));
+        assert_eq!(args.preview_port, Some(8090));
+    }
 
-    let mut server = Server::new(stdin.lock(), stdout.lock());
-    server.test = env::args().any(|arg| arg.contains("test"));
-    server.serve()?;
+    #[test]
+    fn parse_test() {
+        let args = parse(&["--test", "--socket", "5007"]).unwrap();
 
-    Ok(())
+        assert!(args.test);
+        assert_eq!(args.transport, Transport::Socket(5007));
+    }
 }
@@ -0,0 +1,114 @@
+//! Customization of the page that documents are shown in, by the `css` and `template` settings.
+
+use std::fs;
+use std::path::PathBuf;
+
+use log::*;
+
+use crate::settings::Settings;
+
+/// Placeholder in templates for the title of the document.
+const TITLE_PLACEHOLDER: &str = "{{title}}";
+
+/// Placeholder in templates for the rendered document.
+const CONTENT_PLACEHOLDER: &str = "{{content}}";
+
+/// The contents of the stylesheets and template that a document is shown with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Page {
+    pub stylesheets: Vec<String>,
+    pub template: Option<String>,
+}
+
+impl Page {
+    /// Reads the files named by settings. Files that cannot be read are skipped.
+    pub fn load(settings: &Settings) -> Self {
+        let read = |path: &PathBuf| match fs::read_to_string(path) {
+            Ok(contents) => Some(contents),
+            Err(e) => {
+                warn!("could not read {}: {}", path.display(), e);
+                None
+            }
+        };
+
+        Page {
+            stylesheets: settings.css.iter().filter_map(read).collect(),
+            template: settings.template.as_ref().and_then(read),
+        }
+    }
+
+    /// Returns `<style>` elements containing the stylesheets, as made by [`style_element`].
+    pub fn style_elements(&self) -> String {
+        self.stylesheets
+            .iter()
+            .map(|stylesheet| style_element(stylesheet))
+            .collect()
+    }
+
+    /// Fills in the template with the title and content of a document. Without a template, the
+    /// content is returned unchanged.
+    ///
+    /// Previews insert the result into the body of their page, so only exports are shown as a
+    /// whole document.
+    pub fn apply_template(&self, title: &str, content: &str) -> String {
+        match &self.template {
+            // The title is replaced first, so that placeholders in the content are left alone.
+            Some(template) => template
+                .replace(TITLE_PLACEHOLDER, &escape_html(title))
+                .replace(CONTENT_PLACEHOLDER, content),
+            None => content.to_owned(),
+        }
+    }
+}
+
+/// Returns a `<style>` element containing a stylesheet.
+///
+/// Blank lines are removed, since the preview replaces them with comments that are not valid
+/// inside of CSS rules.
+pub fn style_element(stylesheet: &str) -> String {
+    let mut element = String::from("<style>\n");
+
+    for line in stylesheet.lines().filter(|line| !line.trim().is_empty()) {
+        element.push_str(line);
+        element.push('\n');
+    }
+
+    element.push_str("</style>\n");
+    element
+}
+
+/// Returns the files named by settings, which should be watched for changes.
+pub fn files(settings: &Settings) -> impl Iterator<Item = &PathBuf> {
+    settings.css.iter().chain(&settings.template)
+}
+
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Page;
+
+    #[test]
+    fn apply_template() {
+        let page = Page {
+            stylesheets: vec![],
+            template: Some(String::from(
+                "<title>{{title}}</title><main>{{content}}</main>",
+            )),
+        };
+
+        assert_eq!(
+            page.apply_template("a & b", "<p>{{title}}</p>"),
+            "<title>a &amp; b</title><main><p>{{title}}</p></main>"
+        );
+        assert_eq!(
+            Page::default().apply_template("title", "<p></p>"),
+            "<p></p>"
+        );
+    }
+}
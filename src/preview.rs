@@ -0,0 +1,422 @@
+use std::env;
+use std::io::{self, prelude::*};
+use std::process::{Command, Stdio};
+
+use log::*;
+use lsp_types::Url;
+
+use crate::backend::{Copier, Opener, PageEvents, PreviewBackend};
+use crate::page::Page;
+use crate::render;
+use crate::settings::Settings;
+use crate::workers::CancellationToken;
+
+/// A live preview of a single text document.
+///
+/// Each preview owns its own backend, so every open document is served at a distinct URL.
+/// Dropping the preview stops the backend. For the default backend, this closes any connected
+/// browser tabs.
+pub struct Preview {
+    backend: Box<dyn PreviewBackend>,
+
+    /// The title of the document, for the `template` setting.
+    title: String,
+
+    /// The stylesheets and template that the document is shown with.
+    page: Page,
+
+    /// The highlight.js style of code blocks.
+    theme: String,
+
+    /// The external program that renders the markdown, if it is not rendered by mdpls.
+    ///
+    /// External renderers do not annotate their output with source lines, so scrolling is
+    /// unavailable.
+    renderer: Option<(String, Vec<String>)>,
+
+    /// The most recently published markdown.
+    markdown: String,
+
+    /// The most recently rendered HTML.
+    html: String,
+
+    /// The source lines that the top-level blocks of the rendered HTML start on.
+    block_lines: Vec<u64>,
+
+    /// The source line of the block that the preview was last scrolled to.
+    scrolled_block: Option<u64>,
+
+    /// URL that the preview page should request to reveal a source line in the editor.
+    reveal_url: Option<String>,
+
+    /// Channel for sending events to the preview's pages.
+    page_events: Option<PageEvents>,
+
+    /// URL of the page that previews are viewed through when the `offline` setting is enabled.
+    offline_page_url: Option<String>,
+
+    /// The `offline` setting.
+    offline: bool,
+
+    /// The `publicUrl` setting. If set, the preview does not use the event listener.
+    public_url: Option<String>,
+}
+
+impl Preview {
+    pub fn new(
+        backend: Box<dyn PreviewBackend>,
+        settings: &Settings,
+        title: String,
+        reveal_url: Option<String>,
+        page_events: Option<PageEvents>,
+        offline_page_url: Option<String>,
+    ) -> Self {
+        let mut preview = Preview {
+            backend,
+            title,
+            page: Page::default(),
+            theme: String::new(),
+            renderer: None,
+            markdown: String::new(),
+            html: String::new(),
+            block_lines: vec![],
+            scrolled_block: None,
+            reveal_url,
+            page_events,
+            offline_page_url,
+            offline: false,
+            public_url: None,
+        };
+        preview.apply_settings(settings);
+
+        preview
+    }
+
+    /// The URL that the user should visit to view the preview. This is the URL that the preview is
+    /// served at, unless it is rewritten by the `publicUrl` setting or viewed through the offline
+    /// page.
+    pub fn url(&self) -> String {
+        let url = self.backend.url();
+
+        if let Some(public_url) = &self.public_url {
+            return rewrite_url(&url, public_url);
+        }
+
+        match (self.offline_page_url(), &self.page_events) {
+            (Some(page), Some(events)) => {
+                Url::parse_with_params(page, &[("server", &*url), ("events", events.url())])
+                    .expect("invalid preview page URL")
+                    .to_string()
+            }
+            _ => url,
+        }
+    }
+
+    /// Returns the URL of the page that the preview is viewed through instead of the preview
+    /// server's page, which loads KaTeX from a CDN. The page is served by the event listener, so it
+    /// is not used with a public URL.
+    fn offline_page_url(&self) -> Option<&str> {
+        self.offline_page_url
+            .as_deref()
+            .filter(|_| self.offline && self.public_url.is_none())
+    }
+
+    /// Applies settings to the preview. Stylesheets and templates are read again, even if the
+    /// settings are unchanged, so this also reloads them.
+    pub fn apply_settings(&mut self, settings: &Settings) {
+        let public_url_changed = settings.public_url != self.public_url;
+        self.public_url = settings.public_url.clone();
+        self.backend.set_highlight_theme(settings.theme.clone());
+
+        // The event listener is only reachable from this machine, so features that rely on it are
+        // disabled for pages that are viewed through a public URL.
+        let reachable = self.public_url.is_none();
+        let reveal_url = self.reveal_url.clone().filter(|_| reachable);
+        self.backend.set_reveal_url(reveal_url);
+        let page_events = self.page_events.clone().filter(|_| reachable);
+        self.backend.set_page_events(page_events);
+
+        if let Err(e) = self.backend.set_stylesheets(settings.css.clone()) {
+            warn!("could not set stylesheets: {}", e);
+        }
+
+        let renderer_changed = settings.renderer != self.renderer;
+        self.renderer = settings.renderer.clone();
+
+        if settings.offline && (!self.offline || public_url_changed) {
+            if self.public_url.is_some() {
+                warn!(
+                    "the preview of {} has a public URL, so it is not viewed offline",
+                    self.title
+                );
+            } else if self.offline_page_url.is_none() {
+                warn!("the preview of {} cannot be viewed offline", self.title);
+            }
+        }
+
+        self.offline = settings.offline;
+
+        let page = Page::load(settings);
+
+        if page != self.page
+            || settings.theme != self.theme
+            || public_url_changed
+            || renderer_changed
+        {
+            self.page = page;
+            self.theme = settings.theme.clone();
+
+            // Pages that are already open are updated with the new content.
+            if !self.markdown.is_empty() {
+                let result = if renderer_changed {
+                    self.render().and_then(|()| self.publish())
+                } else {
+                    self.publish()
+                };
+
+                if let Err(e) = result {
+                    warn!("could not update preview: {}", e);
+                }
+            }
+        }
+
+        // The offline page includes the preview server's stylesheets itself, and is told which
+        // ones apply.
+        if let Some(events) = &self.page_events {
+            let default_style = self.page.stylesheets.is_empty();
+            events.send("theme", &self.theme);
+            events.send("default-style", &default_style.to_string());
+        }
+
+        // There is currently no way to unset the static root wihout restarting the browser
+        if settings.serve_static {
+            match std::env::current_dir() {
+                Ok(dir) => self.backend.set_static_root(dir),
+                Err(e) => warn!("could not determine static root: {}", e),
+            }
+        }
+    }
+
+    /// Publish new markdown to the preview.
+    pub fn update(&mut self, markdown: &str) -> io::Result<()> {
+        self.markdown = markdown.to_owned();
+        self.render()?;
+        self.publish()
+    }
+
+    /// Scroll the preview to the block containing a line of the source.
+    ///
+    /// The preview is only scrolled if the line is in a different block than before, so that
+    /// moving the cursor within a block does not undo the reader's own scrolling.
+    pub fn scroll_to(&mut self, line: u64) -> io::Result<()> {
+        if self.renderer.is_some() {
+            return Ok(());
+        }
+
+        let block = match self.block_lines.binary_search(&line) {
+            Ok(i) => Some(self.block_lines[i]),
+            Err(0) => None,
+            Err(i) => Some(self.block_lines[i - 1]),
+        };
+
+        if block == self.scrolled_block {
+            return Ok(());
+        }
+
+        self.scrolled_block = block;
+        self.backend.scroll_to(line)
+    }
+
+    pub fn open(&self, browser: Option<&(String, Vec<String>)>) -> io::Result<()> {
+        self.opener(browser)()
+    }
+
+    /// Returns a function that opens the preview, which may be called from another thread.
+    pub fn opener(&self, browser: Option<&(String, Vec<String>)>) -> Opener {
+        self.backend.opener(self.url(), browser)
+    }
+
+    /// Returns a function that copies the preview's URL to the clipboard, which may be called from
+    /// another thread.
+    pub fn copier(&self) -> Copier {
+        self.backend.copier(self.url())
+    }
+
+    /// Tells any connected viewers that the document was closed, and stops the backend.
+    pub fn close(mut self) -> io::Result<()> {
+        self.backend.close()
+    }
+
+    /// Renders the most recent markdown, with the external renderer if it is set.
+    fn render(&mut self) -> io::Result<()> {
+        match &self.renderer {
+            // The renderer is not cancelled, like the preview server's own external renderer.
+            Some(renderer) => {
+                self.html = render::render_external(
+                    renderer,
+                    &self.markdown,
+                    &CancellationToken::default(),
+                )?;
+                self.block_lines.clear();
+            }
+            None => {
+                self.html = render::render(&self.markdown);
+                self.block_lines = render::block_lines(&self.html);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn publish(&mut self) -> io::Result<()> {
+        // Stylesheets are sent with the content, so that open pages are updated when they change.
+        let mut html = self.page.style_elements();
+        html.push_str(&self.html);
+
+        let html = self.page.apply_template(&self.title, &html);
+        self.backend.send(html)
+    }
+}
+
+/// Rewrites the URL that a preview is served at to be relative to a public URL.
+///
+/// The scheme, host and port of `url` are replaced by those of `public_url`. Any `{port}` in
+/// `public_url` is replaced by the port of `url`, so that previews served on different ports can
+/// share the setting.
+fn rewrite_url(url: &str, public_url: &str) -> String {
+    let url = match Url::parse(url) {
+        Ok(url) => url,
+        Err(_) => return public_url.to_owned(),
+    };
+
+    let base = match url.port_or_known_default() {
+        Some(port) => public_url.replace("{port}", &port.to_string()),
+        None => public_url.to_owned(),
+    };
+
+    format!("{}{}", base.trim_end_matches('/'), url.path())
+}
+
+/// Opens a URL in a browser. If `browser` is `None`, the system's default browser is used.
+pub fn open_browser(url: &str, browser: Option<&(String, Vec<String>)>) -> io::Result<()> {
+    let mut command = match browser {
+        Some((name, args)) => {
+            let mut command = Command::new(name);
+            command.args(args);
+            command
+        }
+        None if cfg!(target_os = "macos") => {
+            let mut command = Command::new("open");
+            command.arg("-g");
+            command
+        }
+        None if cfg!(target_os = "windows") => Command::new("explorer"),
+        None => Command::new("xdg-open"),
+    };
+
+    command.arg(url).stdout(Stdio::null()).stderr(Stdio::null());
+
+    info!("spawning browser: {:?}", command);
+    command.spawn()?;
+
+    Ok(())
+}
+
+/// Copies text to the system clipboard. If `cancel` is cancelled, the clipboard program is killed.
+pub fn copy_to_clipboard(text: &str, cancel: &CancellationToken) -> io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("pbcopy")
+    } else if cfg!(target_os = "windows") {
+        Command::new("clip")
+    } else if env::var_os("WAYLAND_DISPLAY").is_some() {
+        Command::new("wl-copy")
+    } else {
+        let mut command = Command::new("xclip");
+        command.args(["-selection", "clipboard"]);
+        command
+    };
+
+    command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    info!("copying to clipboard: {:?}", command);
+    let mut child = command.spawn()?;
+
+    // Close stdin so that the program knows that the text is complete.
+    child.stdin.take().unwrap().write_all(text.as_bytes())?;
+
+    let status = cancel.wait(&mut child)?;
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "{:?} failed: {}",
+            command, status
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::io::prelude::*;
+    use std::net::TcpStream;
+    use std::time::{Duration, Instant};
+
+    use lsp_types::Url;
+
+    use super::{rewrite_url, Preview};
+    use crate::backend::{AureliusBackend, BindAddress};
+    use crate::settings::Settings;
+
+    #[test]
+    fn close_with_idle_connection() -> Result<(), Box<dyn Error>> {
+        let backend = AureliusBackend::bind(&BindAddress {
+            host: String::from("127.0.0.1"),
+            port: 0,
+        })?;
+        let preview = Preview::new(
+            Box::new(backend),
+            &Settings::default(),
+            String::from("idle.md"),
+            None,
+            None,
+            None,
+        );
+
+        // Browsers may open connections that they never use.
+        let url = Url::parse(&preview.url())?;
+        let addr = (url.host_str().unwrap(), url.port().unwrap());
+        let _idle = TcpStream::connect(addr)?;
+
+        // Connections are accepted in order, so the idle connection is being handled once another
+        // request is answered.
+        let mut conn = TcpStream::connect(addr)?;
+        write!(conn, "GET /nonexistent HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+        conn.read_to_string(&mut String::new())?;
+
+        let start = Instant::now();
+        preview.close()?;
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rewrite_public_url() {
+        assert_eq!(
+            rewrite_url("http://127.0.0.1:8090/", "https://example.com"),
+            "https://example.com/"
+        );
+        assert_eq!(
+            rewrite_url("http://127.0.0.1:8090/", "https://example.com/preview/"),
+            "https://example.com/preview/"
+        );
+        assert_eq!(
+            rewrite_url("http://127.0.0.1:8090/", "http://localhost:{port}"),
+            "http://localhost:8090/"
+        );
+    }
+}
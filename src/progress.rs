@@ -0,0 +1,65 @@
+//! Reporting the progress of work done by workers.
+
+use std::sync::mpsc;
+
+use lsp_types::notification::Progress as ProgressNotification;
+use lsp_types::{
+    ProgressParams, ProgressParamsValue, ProgressToken, WorkDoneProgress, WorkDoneProgressBegin,
+    WorkDoneProgressEnd, WorkDoneProgressReport,
+};
+
+use crate::protocol::Notification;
+use crate::Event;
+
+/// Reports the progress of a request to the client with `$/progress` notifications.
+///
+/// Progress is only reported if the client sent a work done token with the request. Clients may
+/// cancel the request from its progress.
+pub struct Progress {
+    token: Option<ProgressToken>,
+    events_tx: mpsc::SyncSender<Event>,
+}
+
+impl Progress {
+    pub fn new(token: Option<ProgressToken>, events_tx: mpsc::SyncSender<Event>) -> Self {
+        Progress { token, events_tx }
+    }
+
+    pub fn begin(&self, title: String) {
+        self.send(WorkDoneProgress::Begin(WorkDoneProgressBegin {
+            title,
+            cancellable: Some(true),
+            message: None,
+            percentage: None,
+        }));
+    }
+
+    pub fn report(&self, message: String) {
+        self.send(WorkDoneProgress::Report(WorkDoneProgressReport {
+            cancellable: None,
+            message: Some(message),
+            percentage: None,
+        }));
+    }
+
+    pub fn end(&self, message: String) {
+        self.send(WorkDoneProgress::End(WorkDoneProgressEnd {
+            message: Some(message),
+        }));
+    }
+
+    fn send(&self, progress: WorkDoneProgress) {
+        let token = match &self.token {
+            Some(token) => token.clone(),
+            None => return,
+        };
+
+        let not = Notification::new::<ProgressNotification>(Some(ProgressParams {
+            token,
+            value: ProgressParamsValue::WorkDone(progress),
+        }));
+
+        // The main loop may have already exited.
+        let _ = self.events_tx.send(Event::Notification(not));
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::io::{self, prelude::*};
 
 use atoi::atoi;
@@ -5,14 +6,42 @@ use httparse::{Status, EMPTY_HEADER};
 use log::*;
 use lsp_types::notification::Notification as LspNotification;
 use lsp_types::request::Request as LspRequest;
-use serde::de::{self, Unexpected};
+use serde::de::{self, DeserializeOwned, Unexpected};
 use serde::ser::SerializeMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use thiserror::Error;
 
+use crate::recording::{Direction, Recorder};
+
 const MAX_HEADERS: usize = 4;
 
+/// Error codes defined by JSON-RPC and the language server protocol.
+pub mod error_codes {
+    pub const PARSE_ERROR: i64 = -32700;
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+    pub const INTERNAL_ERROR: i64 = -32603;
+    pub const SERVER_NOT_INITIALIZED: i64 = -32002;
+    pub const REQUEST_CANCELLED: i64 = -32800;
+}
+
+/// Deserializes the parameters of a request or notification.
+///
+/// Missing parameters are deserialized from `null`, so methods without parameters accept them.
+pub fn parse_params<P>(params: Option<Value>) -> Result<P, ResponseError>
+where
+    P: DeserializeOwned,
+{
+    P::deserialize(params.unwrap_or(Value::Null)).map_err(|e| {
+        ResponseError::new(
+            error_codes::INVALID_PARAMS,
+            format!("invalid params: {}", e),
+        )
+    })
+}
+
 #[derive(Debug, Error)]
 pub enum ProtocolError {
     #[error("I/O error: {0}")]
@@ -59,6 +88,16 @@ pub struct ResponseError {
     pub data: Option<Value>,
 }
 
+impl ResponseError {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        ResponseError {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Request {
     pub id: Value,
@@ -245,9 +284,59 @@ impl<'de> Deserialize<'de> for Message {
     }
 }
 
+/// Requests that have been sent to the other end of the connection, and are awaiting a response.
+///
+/// Each request is associated with a handler of type `H`, which is returned when the response
+/// arrives.
+#[derive(Debug)]
+pub struct OutgoingRequests<H> {
+    next_id: u64,
+    pending: HashMap<u64, H>,
+}
+
+impl<H> Default for OutgoingRequests<H> {
+    fn default() -> Self {
+        OutgoingRequests {
+            next_id: 0,
+            pending: HashMap::new(),
+        }
+    }
+}
+
+impl<H> OutgoingRequests<H> {
+    /// Creates a new request with a unique ID, and registers a handler for its response.
+    pub fn register<R>(&mut self, params: Option<R::Params>, handler: H) -> Request
+    where
+        R: LspRequest,
+        R::Params: Serialize,
+    {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.pending.insert(id, handler);
+
+        Request::new::<R>(Value::from(id), params)
+    }
+
+    /// Returns the handler for the request that a response corresponds to, or `None` if there is
+    /// no such pending request.
+    pub fn complete(&mut self, res: &Response) -> Option<H> {
+        res.id.as_u64().and_then(|id| self.pending.remove(&id))
+    }
+
+    /// Returns the number of requests awaiting a response.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
 pub struct LspTransport<R, W> {
-    reader: buf_redux::BufReader<R>,
-    writer: W,
+    reader: LspReader<R>,
+    writer: LspWriter<W>,
 }
 
 impl<R, W> LspTransport<R, W>
@@ -257,22 +346,51 @@ where
 {
     pub fn new(reader: R, writer: W) -> Self {
         LspTransport {
-            reader: buf_redux::BufReader::new(reader),
-            writer,
+            reader: LspReader::new(reader),
+            writer: LspWriter::new(writer),
         }
     }
 
     pub fn encode(&mut self, message: &Message) -> io::Result<()> {
-        let json = serde_json::to_string(&message).expect("unserializable message");
+        self.writer.encode(message)
+    }
 
-        trace!("<- {}", json);
+    pub fn decode(&mut self) -> Result<Option<Message>, ProtocolError> {
+        self.reader.decode()
+    }
 
-        write!(self.writer, "Content-Length: {}\r\n", json.len())?;
-        write!(self.writer, "\r\n")?;
-        self.writer.write_all(json.as_bytes())?;
-        self.writer.flush()?;
+    /// Records every message that is decoded or encoded by the transport.
+    pub fn set_recorder(&mut self, recorder: Recorder) {
+        self.reader.set_recorder(recorder.clone());
+        self.writer.set_recorder(recorder);
+    }
 
-        Ok(())
+    /// Splits the transport into its reading and writing halves, so that they may be used from
+    /// different threads.
+    pub fn split(self) -> (LspReader<R>, LspWriter<W>) {
+        (self.reader, self.writer)
+    }
+}
+
+/// The reading half of an `LspTransport`.
+pub struct LspReader<R> {
+    reader: buf_redux::BufReader<R>,
+    recorder: Option<Recorder>,
+}
+
+impl<R> LspReader<R>
+where
+    R: Read,
+{
+    pub fn new(reader: R) -> Self {
+        LspReader {
+            reader: buf_redux::BufReader::new(reader),
+            recorder: None,
+        }
+    }
+
+    pub fn set_recorder(&mut self, recorder: Recorder) {
+        self.recorder = Some(recorder);
     }
 
     pub fn decode(&mut self) -> Result<Option<Message>, ProtocolError> {
@@ -285,7 +403,7 @@ where
 
             let mut headers = [EMPTY_HEADER; MAX_HEADERS];
 
-            match httparse::parse_headers(&buf, &mut headers)? {
+            match httparse::parse_headers(buf, &mut headers)? {
                 Status::Partial => {
                     self.reader.read_into_buf()?;
                 }
@@ -293,12 +411,12 @@ where
                     let content_length_header = parsed
                         .iter()
                         .find(|header| header.name == "Content-Length")
-                        .ok_or_else(|| ProtocolError::MissingContentLength)?;
+                        .ok_or(ProtocolError::MissingContentLength)?;
 
                     break (
                         n,
                         atoi(content_length_header.value)
-                            .ok_or_else(|| ProtocolError::InvalidContentLength)?,
+                            .ok_or(ProtocolError::InvalidContentLength)?,
                     );
                 }
             }
@@ -312,7 +430,58 @@ where
 
         trace!("-> {}", String::from_utf8_lossy(&json_buf));
 
-        Ok(Some(serde_json::from_slice(&json_buf)?))
+        let message = serde_json::from_slice(&json_buf)?;
+
+        if let Some(recorder) = &self.recorder {
+            // The JSON was valid, so it must also be valid UTF-8.
+            recorder.record(Direction::Received, &String::from_utf8_lossy(&json_buf));
+        }
+
+        Ok(Some(message))
+    }
+}
+
+/// The writing half of an `LspTransport`.
+pub struct LspWriter<W> {
+    writer: W,
+    recorder: Option<Recorder>,
+}
+
+impl<W> LspWriter<W>
+where
+    W: Write,
+{
+    pub fn new(writer: W) -> Self {
+        LspWriter {
+            writer,
+            recorder: None,
+        }
+    }
+
+    pub fn set_recorder(&mut self, recorder: Recorder) {
+        self.recorder = Some(recorder);
+    }
+
+    /// Returns the underlying writer, such as for writing data that is not a message.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    pub fn encode(&mut self, message: &Message) -> io::Result<()> {
+        let json = serde_json::to_string(&message).expect("unserializable message");
+
+        trace!("<- {}", json);
+
+        if let Some(recorder) = &self.recorder {
+            recorder.record(Direction::Sent, &json);
+        }
+
+        write!(self.writer, "Content-Length: {}\r\n", json.len())?;
+        write!(self.writer, "\r\n")?;
+        self.writer.write_all(json.as_bytes())?;
+        self.writer.flush()?;
+
+        Ok(())
     }
 }
 
@@ -322,10 +491,11 @@ mod tests {
     use std::io::{self, Read};
 
     use assert_matches::assert_matches;
+    use lsp_types::lsp_request;
     use serde::Deserialize;
     use serde_json::{self, json, Value};
 
-    use super::{LspTransport, Message, ProtocolError};
+    use super::{LspTransport, Message, OutgoingRequests, ProtocolError};
 
     #[test]
     fn deseialize_request_string_id() {
@@ -425,6 +595,34 @@ mod tests {
         assert!(err.to_string().contains("missing field `method`"));
     }
 
+    #[test]
+    fn outgoing_requests() -> Result<(), Box<dyn Error>> {
+        let mut requests = OutgoingRequests::default();
+
+        let first = requests.register::<lsp_request!("shutdown")>(None, "first");
+        let second = requests.register::<lsp_request!("shutdown")>(None, "second");
+
+        assert_ne!(first.id, second.id);
+        assert_eq!(requests.len(), 2);
+
+        let response = |id: &Value| {
+            Message::deserialize(json!({ "jsonrpc": "2.0", "id": id, "result": null }))
+        };
+
+        let res = assert_matches!(response(&second.id)?, Message::Response(res) => res);
+        assert_eq!(requests.complete(&res), Some("second"));
+        assert_eq!(requests.complete(&res), None);
+
+        let res = assert_matches!(response(&json!("unknown"))?, Message::Response(res) => res);
+        assert_eq!(requests.complete(&res), None);
+
+        let res = assert_matches!(response(&first.id)?, Message::Response(res) => res);
+        assert_eq!(requests.complete(&res), Some("first"));
+        assert!(requests.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn decode_multiple_frames() {
         let frames = concat!(
@@ -0,0 +1,201 @@
+//! Recording of the messages exchanged over a transport, so that sessions can be replayed later.
+//!
+//! A recording is a JSON Lines file. Each line is an object with the following fields:
+//!
+//! - `timestamp`: milliseconds since the Unix epoch when the message was sent or received.
+//! - `direction`: `"received"` if the message was decoded by the transport, or `"sent"` if it was
+//!   encoded.
+//! - `message`: the JSON-RPC message.
+
+use std::fs::File;
+use std::io::{self, prelude::*, BufReader};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::*;
+use lsp_types::lsp_request;
+use lsp_types::request::Request as LspRequest;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::protocol::{LspWriter, Message, Request};
+
+/// Whether a message was received or sent by the recording transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Received,
+    Sent,
+}
+
+/// Appends messages to a recording. Clones of a recorder write to the same file.
+#[derive(Clone)]
+pub struct Recorder {
+    file: Arc<Mutex<File>>,
+}
+
+impl Recorder {
+    /// Creates a recording at `path`, truncating any existing file.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Recorder {
+            file: Arc::new(Mutex::new(File::create(path)?)),
+        })
+    }
+
+    /// Records a message that has already been serialized to JSON.
+    pub(crate) fn record(&self, direction: Direction, json: &str) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis())
+            .unwrap_or_default();
+
+        let direction = serde_json::to_string(&direction).expect("could not serialize direction");
+
+        // The message is already valid JSON, so it is inserted as-is rather than being parsed
+        // again.
+        let line = format!(
+            r#"{{"timestamp":{},"direction":{},"message":{}}}"#,
+            timestamp, direction, json
+        );
+
+        let mut file = self.file.lock().unwrap();
+
+        if let Err(e) = writeln!(file, "{}", line).and_then(|()| file.flush()) {
+            warn!("could not record message: {}", e);
+        }
+    }
+}
+
+/// A message in a recording.
+#[derive(Debug, Deserialize)]
+pub struct Entry {
+    pub timestamp: u64,
+    pub direction: Direction,
+    pub message: Message,
+}
+
+/// Reads every entry of a recording.
+pub fn read(path: impl AsRef<Path>) -> io::Result<Vec<Entry>> {
+    let reader = BufReader::new(File::open(path)?);
+
+    reader
+        .lines()
+        .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|line| {
+            serde_json::from_str(&line?).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .collect()
+}
+
+/// Encodes the messages that were received in a recording, so that they can be fed to a server
+/// as if they were sent by the recorded client.
+///
+/// The ID of the client's process is removed from `initialize`, since the recorded client is not
+/// running. Otherwise, the server would exit as soon as it noticed.
+pub fn replay_input(entries: &[Entry]) -> io::Result<Vec<u8>> {
+    let mut input = Vec::new();
+
+    {
+        let mut writer = LspWriter::new(&mut input);
+
+        for entry in entries {
+            if entry.direction != Direction::Received {
+                continue;
+            }
+
+            match &entry.message {
+                Message::Request(req) if req.method == <lsp_request!("initialize")>::METHOD => {
+                    let mut params = req.params.clone();
+
+                    if let Some(Value::Object(params)) = &mut params {
+                        params.insert(String::from("processId"), Value::Null);
+                    }
+
+                    writer.encode(&Message::Request(Request {
+                        id: req.id.clone(),
+                        method: req.method.clone(),
+                        params,
+                    }))?;
+                }
+                message => writer.encode(message)?,
+            }
+        }
+    }
+
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::error::Error;
+    use std::fs;
+    use std::io::Cursor;
+    use std::process;
+
+    use serde_json::json;
+
+    use super::{read, replay_input, Direction, Recorder};
+    use crate::protocol::{LspTransport, Message, Request};
+
+    #[test]
+    fn record_and_replay() -> Result<(), Box<dyn Error>> {
+        let path = env::temp_dir().join(format!("mdpls-recording-{}.jsonl", process::id()));
+
+        let recorder = Recorder::create(&path)?;
+        recorder.record(
+            Direction::Received,
+            r#"{"jsonrpc":"2.0","method":"initialized"}"#,
+        );
+        recorder.record(
+            Direction::Sent,
+            r#"{"jsonrpc":"2.0","id":0,"method":"workspace/configuration"}"#,
+        );
+
+        let entries = read(&path)?;
+        fs::remove_file(&path)?;
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].direction, Direction::Received);
+        assert_eq!(entries[1].direction, Direction::Sent);
+
+        let input = replay_input(&entries)?;
+        let mut transport = LspTransport::new(Cursor::new(input), Vec::new());
+
+        let not = match transport.decode()? {
+            Some(Message::Notification(not)) => not,
+            message => panic!("expected notification, got {:?}", message),
+        };
+        assert_eq!(not.method, "initialized");
+
+        // Sent messages are not replayed.
+        assert!(transport.decode()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn record_transport() -> Result<(), Box<dyn Error>> {
+        let path = env::temp_dir().join(format!("mdpls-transport-{}.jsonl", process::id()));
+
+        let mut transport = LspTransport::new(Cursor::new(Vec::new()), Vec::new());
+        transport.set_recorder(Recorder::create(&path)?);
+
+        let req = Request {
+            id: json!(1),
+            method: String::from("shutdown"),
+            params: None,
+        };
+        transport.encode(&Message::Request(req))?;
+
+        let entries = read(&path)?;
+        fs::remove_file(&path)?;
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].direction, Direction::Sent);
+        assert!(matches!(&entries[0].message, Message::Request(req) if req.method == "shutdown"));
+
+        Ok(())
+    }
+}
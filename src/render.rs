@@ -0,0 +1,296 @@
+//! Rendering of markdown to HTML for the preview.
+
+use std::io;
+use std::iter;
+use std::process::Command;
+
+use pulldown_cmark::{html, Event, Options, Parser};
+
+use crate::export;
+use crate::workers::CancellationToken;
+
+/// Comment inserted before each top-level block during rendering, and replaced by an attribute
+/// on the block's start tag afterwards.
+const SOURCE_LINE_MARKER: &str = "<!--mdpls-source-line:";
+
+/// Function that scrolls the preview to the block containing a source line.
+const SCROLL_FUNCTION: &str = "\
+function(line) {\
+    var target = null, blocks = document.querySelectorAll('[data-source-line]');\
+    for (var i = 0; i < blocks.length; i++) {\
+        if (parseInt(blocks[i].getAttribute('data-source-line'), 10) > line) break;\
+        target = blocks[i];\
+    }\
+    if (target) target.scrollIntoView();\
+}";
+
+/// Script that subscribes to events from the language server, such as scrolling.
+///
+/// The page only subscribes once, even though the script is sent with every render. The offline
+/// page may have subscribed already.
+const SUBSCRIBE_SCRIPT: &str = "\
+if (!window.mdplsScrolling) {\
+    window.mdplsScrolling = true;\
+    window.mdplsEvents = window.mdplsEvents || new EventSource('{url}');\
+    mdplsEvents.addEventListener('scroll', function(event) {\
+        ({scroll})(parseInt(event.data, 10));\
+    });\
+}";
+
+/// Script that reports double-clicked blocks to the language server, so that the editor can reveal
+/// the block's source line.
+///
+/// The listener is only installed once per page, but the URL is updated on every render.
+const REVEAL_SCRIPT: &str = "\
+if (!window.mdplsRevealUrl) {\
+    document.addEventListener('dblclick', function(event) {\
+        var block = event.target.closest('[data-source-line]');\
+        if (block) new Image().src = window.mdplsRevealUrl + block.getAttribute('data-source-line');\
+    });\
+}\
+window.mdplsRevealUrl = '{url}';";
+
+/// Renders markdown to HTML.
+///
+/// Each top-level block is annotated with the zero-based line that it starts on in the source,
+/// as a `data-source-line` attribute.
+pub fn render(markdown: &str) -> String {
+    let line_starts = iter::once(0)
+        .chain(markdown.match_indices('\n').map(|(i, _)| i + 1))
+        .collect::<Vec<_>>();
+
+    let line_of = |offset| match line_starts.binary_search(&offset) {
+        Ok(line) => line,
+        Err(line) => line - 1,
+    };
+
+    let mut depth = 0usize;
+
+    let parser = Parser::new_ext(markdown, options()).into_offset_iter();
+    let events = parser.flat_map(|(event, range)| {
+        let marker = match event {
+            Event::Start(_) if depth == 0 => Some(Event::Html(
+                format!("{}{}-->\n", SOURCE_LINE_MARKER, line_of(range.start)).into(),
+            )),
+            _ => None,
+        };
+
+        match event {
+            Event::Start(_) => depth += 1,
+            Event::End(_) => depth -= 1,
+            _ => (),
+        }
+
+        marker.into_iter().chain(iter::once(event))
+    });
+
+    let mut html = String::with_capacity(markdown.len());
+    html::push_html(&mut html, events);
+
+    annotate_source_lines(&html)
+}
+
+/// Returns the source lines of the blocks annotated by [`render`], in the order that they appear.
+pub fn block_lines(html: &str) -> Vec<u64> {
+    const ATTRIBUTE: &str = " data-source-line=\"";
+
+    html.match_indices(ATTRIBUTE)
+        .filter_map(|(i, _)| {
+            let value = &html[i + ATTRIBUTE.len()..];
+            value[..value.find('"')?].parse().ok()
+        })
+        .collect()
+}
+
+/// Renders markdown to HTML with an external program, which reads markdown on stdin and writes
+/// HTML to stdout. The output is not annotated with source lines.
+pub fn render_external(
+    renderer: &(String, Vec<String>),
+    markdown: &str,
+    cancel: &CancellationToken,
+) -> io::Result<String> {
+    let mut command = Command::new(&renderer.0);
+    command.args(&renderer.1);
+
+    let html = export::pipe(&mut command, markdown.as_bytes(), cancel)?;
+    String::from_utf8(html).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Returns an element that scrolls the preview to the block containing `line` when it is inserted
+/// into the page.
+pub fn scroll_to_line(line: u64) -> String {
+    script_element(&format!("({})({});", SCROLL_FUNCTION, line))
+}
+
+/// Returns an element that subscribes the page to the server-sent events at `url` when it is
+/// inserted into the page.
+///
+/// The URL must not contain quotes or backslashes.
+pub fn subscribe_to_events(url: &str) -> String {
+    script_element(
+        &SUBSCRIBE_SCRIPT
+            .replace("{url}", url)
+            .replace("{scroll}", SCROLL_FUNCTION),
+    )
+}
+
+/// Returns an element that reports double-clicked blocks to `url` when it is inserted into the
+/// page. The source line of the block is appended to the URL.
+///
+/// The URL must not contain quotes or backslashes.
+pub fn reveal_on_double_click(url: &str) -> String {
+    script_element(&REVEAL_SCRIPT.replace("{url}", url))
+}
+
+/// Wraps rendered HTML so that it is passed through unchanged if it is rendered as markdown.
+///
+/// The preview server renders everything it receives as markdown. A markdown HTML block that
+/// starts with a `<div>` continues until the first blank line, so the HTML is wrapped in a `<div>`
+/// and every blank line is replaced with an empty comment.
+pub fn as_html_block(html: &str) -> String {
+    let mut block = String::with_capacity(html.len() + 32);
+
+    block.push_str("<div class=\"mdpls-preview\">\n");
+
+    for line in html.lines() {
+        if line.trim().is_empty() {
+            block.push_str("<!---->");
+        } else {
+            block.push_str(line);
+        }
+        block.push('\n');
+    }
+
+    block.push_str("</div>\n");
+
+    block
+}
+
+/// Returns an element that runs a script when it is inserted into the page.
+///
+/// The preview page replaces its content using `innerHTML`, so `<script>` tags are never executed.
+/// Event handlers are, however, so the script runs from the `error` event of an image that can
+/// never load.
+fn script_element(script: &str) -> String {
+    format!(
+        r#"<img hidden src="data:," onerror="{}">"#,
+        script.replace('&', "&amp;").replace('"', "&quot;")
+    )
+}
+
+fn options() -> Options {
+    Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+}
+
+/// Replaces each source line marker with a `data-source-line` attribute on the tag that follows
+/// it.
+fn annotate_source_lines(html: &str) -> String {
+    let mut annotated = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find(SOURCE_LINE_MARKER) {
+        annotated.push_str(&rest[..start]);
+
+        let marker = &rest[start + SOURCE_LINE_MARKER.len()..];
+
+        let end = match marker.find("-->\n") {
+            Some(end) => end,
+            None => {
+                rest = &rest[start..];
+                break;
+            }
+        };
+
+        let line = &marker[..end];
+        rest = &marker[end + "-->\n".len()..];
+
+        let tag_name_len = rest
+            .strip_prefix('<')
+            .map(|tag| {
+                tag.find(|c: char| !c.is_ascii_alphanumeric())
+                    .unwrap_or(tag.len())
+            })
+            .unwrap_or(0);
+
+        if tag_name_len > 0 {
+            let (tag, remainder) = rest.split_at(tag_name_len + 1);
+            annotated.push_str(tag);
+            annotated.push_str(&format!(" data-source-line=\"{}\"", line));
+            rest = remainder;
+        }
+    }
+
+    annotated.push_str(rest);
+
+    annotated
+}
+
+#[cfg(test)]
+mod tests {
+    use pulldown_cmark::{html, Parser};
+
+    use super::{
+        as_html_block, block_lines, render, reveal_on_double_click, scroll_to_line,
+        subscribe_to_events,
+    };
+
+    #[test]
+    fn annotate_top_level_blocks() {
+        let html = render("# Title\n\nSome *text*.\n\n- one\n- two\n");
+
+        assert_eq!(
+            html,
+            concat!(
+                "<h1 data-source-line=\"0\">Title</h1>\n",
+                "<p data-source-line=\"2\">Some <em>text</em>.</p>\n",
+                "<ul data-source-line=\"4\">\n",
+                "<li>one</li>\n",
+                "<li>two</li>\n",
+                "</ul>\n",
+            )
+        );
+    }
+
+    #[test]
+    fn find_block_lines() {
+        let html = render("# Title\n\nSome\ntext.\n\n- one\n- two\n");
+
+        assert_eq!(block_lines(&html), vec![0, 2, 5]);
+    }
+
+    #[test]
+    fn annotate_code_block() {
+        let html = render("text\n\n```rust\nfn main() {}\n```\n");
+
+        assert_eq!(
+            html,
+            concat!(
+                "<p data-source-line=\"0\">text</p>\n",
+                "<pre data-source-line=\"2\"><code class=\"language-rust\">fn main() {}\n",
+                "</code></pre>\n",
+            )
+        );
+    }
+
+    #[test]
+    fn html_block_passes_through_markdown() {
+        let mut html = render("# Code\n\n```\na\n\n  \nb\n```\n\n<p>\n\n</p>\n");
+        html.push_str(&scroll_to_line(2));
+        html.push_str(&reveal_on_double_click(
+            "http://localhost:1234/reveal?uri=a&line=",
+        ));
+        html.push_str(&subscribe_to_events(
+            "http://localhost:1234/events?token=a&uri=b",
+        ));
+
+        let block = as_html_block(&html);
+
+        let mut rendered = String::new();
+        html::push_html(&mut rendered, Parser::new(&block));
+
+        assert_eq!(rendered, block);
+    }
+}
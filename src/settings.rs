@@ -0,0 +1,647 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use log::LevelFilter;
+use lsp_types::Url;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Settings {
+    /// Auto-open the preview.
+    pub auto: bool,
+
+    /// Program and arguments to use to open the preview. If `None`, use the default browser.
+    pub browser: Option<(String, Vec<String>)>,
+
+    /// highlight.js style to use for syntax highlighting in code blocks.
+    pub theme: String,
+
+    /// Serve static files like images. This should only be use with trusted documents.
+    pub serve_static: bool,
+
+    /// Program and arguments to use to render the markdown. If `None`, use the default renderer.
+    pub renderer: Option<(String, Vec<String>)>,
+
+    /// The most verbose log records that are sent to the client.
+    pub log_level: LevelFilter,
+
+    /// File to append log records to.
+    pub log_file: Option<PathBuf>,
+
+    /// Host that previews are served on.
+    pub host: String,
+
+    /// Port that previews are served on. If 0, a port is chosen by `stable_port` or at random.
+    pub port: u16,
+
+    /// Derive the port from the document.
+    pub stable_port: bool,
+
+    /// URL that replaces the scheme, host and port of preview URLs that are shown to the user.
+    pub public_url: Option<String>,
+
+    /// Send preview URLs to the client instead of opening a browser.
+    pub headless: bool,
+
+    /// Stop serving previews once no documents are open.
+    pub stop_when_idle: bool,
+
+    /// Commands that export documents, keyed by the name of the format that they export to.
+    pub exporters: HashMap<String, Exporter>,
+
+    /// Stylesheets that replace the default stylesheet.
+    pub css: Vec<PathBuf>,
+
+    /// HTML file that documents are shown in, with `{{title}}` and `{{content}}` placeholders.
+    pub template: Option<PathBuf>,
+
+    /// View previews through a page that only loads bundled assets, instead of the preview
+    /// server's page, which loads KaTeX from a CDN.
+    pub offline: bool,
+}
+
+impl Settings {
+    /// Deserializes settings from the value of the `markdown.preview` configuration section.
+    pub fn from_section(section: Value) -> serde_json::Result<Self> {
+        let preview_settings = Option::<PreviewSettings>::deserialize(section)?;
+        Ok(preview_settings.map(Settings::from).unwrap_or_default())
+    }
+
+    /// Resolves the relative paths of the stylesheets and template against a directory, such as
+    /// the workspace folder that the settings are scoped to.
+    pub fn resolve_paths(&mut self, dir: &Path) {
+        for path in self.css.iter_mut().chain(&mut self.template) {
+            *path = dir.join(&*path);
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            auto: true,
+            browser: None,
+            theme: String::from("github"),
+            serve_static: false,
+            renderer: None,
+            log_level: LevelFilter::Warn,
+            log_file: None,
+            host: String::from("localhost"),
+            port: 0,
+            stable_port: false,
+            public_url: None,
+            headless: false,
+            stop_when_idle: false,
+            exporters: HashMap::new(),
+            css: vec![],
+            template: None,
+            offline: false,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Settings {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Settings {
+            markdown: Option<Markdown>,
+        }
+
+        #[derive(Deserialize)]
+        struct Markdown {
+            preview: Option<PreviewSettings>,
+        }
+
+        Settings::deserialize(deserializer).map(|settings| {
+            settings
+                .markdown
+                .and_then(|markdown| markdown.preview)
+                .map(crate::settings::Settings::from)
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// The `markdown.preview` configuration section.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PreviewSettings {
+    auto: Option<bool>,
+    #[serde(deserialize_with = "deserialize_opt_command")]
+    #[serde(default)]
+    browser: Option<(String, Vec<String>)>,
+    code_theme: Option<String>,
+    serve_static: Option<bool>,
+    #[serde(deserialize_with = "deserialize_opt_command")]
+    #[serde(default)]
+    renderer: Option<(String, Vec<String>)>,
+    #[serde(deserialize_with = "deserialize_opt_level_filter")]
+    #[serde(default)]
+    log_level: Option<LevelFilter>,
+    log_file: Option<PathBuf>,
+    host: Option<String>,
+    port: Option<u16>,
+    stable_port: Option<bool>,
+    public_url: Option<String>,
+    headless: Option<bool>,
+    stop_when_idle: Option<bool>,
+    exporters: Option<HashMap<String, Exporter>>,
+    #[serde(deserialize_with = "deserialize_opt_paths")]
+    #[serde(default)]
+    css: Option<Vec<PathBuf>>,
+    template: Option<PathBuf>,
+    offline: Option<bool>,
+}
+
+impl From<PreviewSettings> for Settings {
+    fn from(preview_settings: PreviewSettings) -> Self {
+        let mut settings = Settings::default();
+
+        if let Some(auto) = preview_settings.auto {
+            settings.auto = auto;
+        }
+
+        if let Some(theme) = preview_settings.code_theme {
+            settings.theme = theme;
+        }
+
+        settings.browser = preview_settings.browser;
+
+        if let Some(serve_static) = preview_settings.serve_static {
+            settings.serve_static = serve_static;
+        }
+
+        settings.renderer = preview_settings.renderer;
+
+        if let Some(log_level) = preview_settings.log_level {
+            settings.log_level = log_level;
+        }
+
+        settings.log_file = preview_settings.log_file;
+
+        if let Some(host) = preview_settings.host {
+            settings.host = host;
+        }
+
+        if let Some(port) = preview_settings.port {
+            settings.port = port;
+        }
+
+        if let Some(stable_port) = preview_settings.stable_port {
+            settings.stable_port = stable_port;
+        }
+
+        settings.public_url = preview_settings.public_url;
+
+        if let Some(headless) = preview_settings.headless {
+            settings.headless = headless;
+        }
+
+        if let Some(stop_when_idle) = preview_settings.stop_when_idle {
+            settings.stop_when_idle = stop_when_idle;
+        }
+
+        if let Some(exporters) = preview_settings.exporters {
+            settings.exporters = exporters;
+        }
+
+        if let Some(css) = preview_settings.css {
+            settings.css = css;
+        }
+
+        settings.template = preview_settings.template;
+
+        if let Some(offline) = preview_settings.offline {
+            settings.offline = offline;
+        }
+
+        settings
+    }
+}
+
+/// A program that exports documents to another format.
+///
+/// In the configuration, this is either a command, or an object with `command` and `input` keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exporter {
+    /// Program and arguments to run. Any `{output}` in the arguments is replaced by the path of
+    /// the exported file. If there is no `{output}`, the program's stdout is written to the file.
+    pub command: (String, Vec<String>),
+
+    /// What is piped to the program's stdin.
+    pub input: ExportInput,
+}
+
+impl<'de> Deserialize<'de> for Exporter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Exporter {
+            Command(#[serde(deserialize_with = "deserialize_command")] (String, Vec<String>)),
+            Options {
+                #[serde(deserialize_with = "deserialize_command")]
+                command: (String, Vec<String>),
+                #[serde(default)]
+                input: ExportInput,
+            },
+        }
+
+        Ok(match Exporter::deserialize(deserializer)? {
+            Exporter::Command(command) => crate::settings::Exporter {
+                command,
+                input: ExportInput::default(),
+            },
+            Exporter::Options { command, input } => crate::settings::Exporter { command, input },
+        })
+    }
+}
+
+/// The input of an [`Exporter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportInput {
+    /// The document as a standalone HTML page, as written by the `Export HTML` command.
+    #[default]
+    Html,
+
+    /// The markdown source of the document.
+    Markdown,
+}
+
+/// Settings for the workspace as a whole, and for each workspace folder.
+#[derive(Debug, Default)]
+pub struct Configuration {
+    pub global: Settings,
+
+    /// Settings scoped to a workspace folder, keyed by the folder's URI.
+    pub folders: HashMap<Url, Settings>,
+}
+
+impl Configuration {
+    /// Returns the settings that apply to a document.
+    ///
+    /// These are the settings of the innermost workspace folder that contains the document, or
+    /// the global settings if no folder contains it.
+    pub fn for_document(&self, uri: &Url) -> &Settings {
+        self.folders
+            .iter()
+            .filter(|(folder, _)| contains(folder, uri))
+            .max_by_key(|(folder, _)| folder.as_str().len())
+            .map(|(_, settings)| settings)
+            .unwrap_or(&self.global)
+    }
+}
+
+/// Returns true if `uri` is inside of the folder at `folder`.
+pub fn contains(folder: &Url, uri: &Url) -> bool {
+    let folder = folder.as_str().trim_end_matches('/');
+
+    uri.as_str()
+        .strip_prefix(folder)
+        .is_some_and(|rest| rest.starts_with('/'))
+}
+
+fn deserialize_command<'de, D>(deserializer: D) -> Result<(String, Vec<String>), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct CommandVisitor;
+
+    impl<'de> Visitor<'de> for CommandVisitor {
+        type Value = (String, Vec<String>);
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "string or array of strings")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok((String::from(value), vec![]))
+        }
+
+        fn visit_seq<S>(self, seq: S) -> Result<Self::Value, S::Error>
+        where
+            S: de::SeqAccess<'de>,
+        {
+            let args = <Vec<String>>::deserialize(de::value::SeqAccessDeserializer::new(seq))?;
+            let (program, args) = args
+                .split_first()
+                .ok_or_else(|| de::Error::invalid_length(0, &"at least a program name"))?;
+
+            Ok((String::from(program), args.to_vec()))
+        }
+    }
+
+    deserializer.deserialize_any(CommandVisitor)
+}
+
+fn deserialize_opt_command<'de, D>(
+    deserializer: D,
+) -> Result<Option<(String, Vec<String>)>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // serde#723
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize_command")] (String, Vec<String>));
+
+    let v = Option::deserialize(deserializer)?;
+    Ok(v.map(|Wrapper(command)| command))
+}
+
+/// Deserializes a path, or an array of paths.
+fn deserialize_opt_paths<'de, D>(deserializer: D) -> Result<Option<Vec<PathBuf>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Paths {
+        One(PathBuf),
+        Many(Vec<PathBuf>),
+    }
+
+    Ok(Option::deserialize(deserializer)?.map(|paths| match paths {
+        Paths::One(path) => vec![path],
+        Paths::Many(paths) => paths,
+    }))
+}
+
+fn deserialize_opt_level_filter<'de, D>(deserializer: D) -> Result<Option<LevelFilter>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|level| level.parse().map_err(de::Error::custom))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::error::Error;
+    use std::path::{Path, PathBuf};
+
+    use log::LevelFilter;
+    use lsp_types::Url;
+    use serde::Deserialize;
+    use serde_json::{json, Value};
+
+    use super::{Configuration, ExportInput, Exporter, Settings};
+
+    #[test]
+    fn deserialize_empty_settings() -> Result<(), Box<dyn Error>> {
+        let json = json!({});
+
+        assert_eq!(Settings::deserialize(json)?, Settings::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_empty_markdown_settings() -> Result<(), Box<dyn Error>> {
+        let json = json!({
+            "markdown": {}
+        });
+
+        assert_eq!(Settings::deserialize(json)?, Settings::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_empty_preview_settings() -> Result<(), Box<dyn Error>> {
+        let json = json!({
+            "markdown": {
+                "preview": {}
+            }
+        });
+
+        assert_eq!(Settings::deserialize(json)?, Settings::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_settings() -> Result<(), Box<dyn Error>> {
+        let json = json!({
+            "markdown": {
+                "preview": {
+                    "auto": false,
+                    "browser": "firefox"
+                }
+            }
+        });
+
+        let settings = Settings::deserialize(json)?;
+
+        assert!(!settings.auto);
+        assert_eq!(settings.browser, Some((String::from("firefox"), vec![])));
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_browser_list() -> Result<(), Box<dyn Error>> {
+        let json = json!({
+            "markdown": {
+                "preview": {
+                    "browser": ["open", "-g"]
+                }
+            }
+        });
+
+        let settings = Settings::deserialize(json)?;
+
+        assert_eq!(
+            settings.browser,
+            Some((String::from("open"), vec![String::from("-g")]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_theme() -> Result<(), Box<dyn Error>> {
+        let json = json!({
+            "markdown": {
+                "preview": {
+                    "codeTheme": "darcula"
+                }
+            }
+        });
+
+        let settings = Settings::deserialize(json)?;
+
+        assert_eq!(settings.theme, "darcula");
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_log_level() -> Result<(), Box<dyn Error>> {
+        let settings = Settings::from_section(json!({ "logLevel": "info" }))?;
+        assert_eq!(settings.log_level, LevelFilter::Info);
+
+        assert!(Settings::from_section(json!({ "logLevel": "loud" })).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_address() -> Result<(), Box<dyn Error>> {
+        let settings = Settings::from_section(json!({
+            "host": "0.0.0.0",
+            "port": 8090,
+            "stablePort": true
+        }))?;
+
+        assert_eq!(settings.host, "0.0.0.0");
+        assert_eq!(settings.port, 8090);
+        assert!(settings.stable_port);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_exporters() -> Result<(), Box<dyn Error>> {
+        let settings = Settings::from_section(json!({
+            "exporters": {
+                "pdf": ["wkhtmltopdf", "-", "{output}"],
+                "docx": {
+                    "command": ["pandoc", "-o", "{output}"],
+                    "input": "markdown"
+                }
+            }
+        }))?;
+
+        assert_eq!(
+            settings.exporters["pdf"],
+            Exporter {
+                command: (
+                    String::from("wkhtmltopdf"),
+                    vec![String::from("-"), String::from("{output}")]
+                ),
+                input: ExportInput::Html,
+            }
+        );
+        assert_eq!(settings.exporters["docx"].input, ExportInput::Markdown);
+
+        assert!(Settings::from_section(json!({ "exporters": { "pdf": [] } })).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_css() -> Result<(), Box<dyn Error>> {
+        let settings = Settings::from_section(json!({ "css": "wiki.css" }))?;
+        assert_eq!(settings.css, vec![PathBuf::from("wiki.css")]);
+
+        let settings = Settings::from_section(json!({
+            "css": ["wiki.css", "/etc/print.css"],
+            "template": "wiki.html"
+        }))?;
+        assert_eq!(
+            settings.css,
+            vec![PathBuf::from("wiki.css"), PathBuf::from("/etc/print.css")]
+        );
+        assert_eq!(settings.template, Some(PathBuf::from("wiki.html")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_paths() -> Result<(), Box<dyn Error>> {
+        if cfg!(windows) {
+            return Ok(());
+        }
+
+        let mut settings = Settings::from_section(json!({
+            "css": ["wiki.css", "/etc/print.css"],
+            "template": "../wiki.html"
+        }))?;
+        settings.resolve_paths(Path::new("/home/user/project"));
+
+        assert_eq!(
+            settings.css,
+            vec![
+                PathBuf::from("/home/user/project/wiki.css"),
+                PathBuf::from("/etc/print.css")
+            ]
+        );
+        assert_eq!(
+            settings.template,
+            Some(PathBuf::from("/home/user/project/../wiki.html"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_section() -> Result<(), Box<dyn Error>> {
+        let json = json!({
+            "auto": false,
+            "codeTheme": "darcula"
+        });
+
+        let settings = Settings::from_section(json)?;
+
+        assert!(!settings.auto);
+        assert_eq!(settings.theme, "darcula");
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_null_section() -> Result<(), Box<dyn Error>> {
+        assert_eq!(Settings::from_section(Value::Null)?, Settings::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn settings_for_document() -> Result<(), Box<dyn Error>> {
+        let mut folders = HashMap::new();
+        folders.insert(
+            Url::parse("file:///project")?,
+            Settings {
+                theme: String::from("project"),
+                ..Settings::default()
+            },
+        );
+        folders.insert(
+            Url::parse("file:///project/docs/")?,
+            Settings {
+                theme: String::from("docs"),
+                ..Settings::default()
+            },
+        );
+
+        let configuration = Configuration {
+            global: Settings::default(),
+            folders,
+        };
+
+        let theme = |uri| -> Result<String, Box<dyn Error>> {
+            Ok(configuration.for_document(&Url::parse(uri)?).theme.clone())
+        };
+
+        assert_eq!(theme("file:///project/README.md")?, "project");
+        assert_eq!(theme("file:///project/docs/index.md")?, "docs");
+        assert_eq!(theme("file:///project-other/README.md")?, "github");
+        assert_eq!(theme("file:///README.md")?, "github");
+
+        Ok(())
+    }
+}
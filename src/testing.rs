@@ -0,0 +1,649 @@
+//! An in-process client for testing integrations with mdpls.
+//!
+//! [`Client`] runs a [`Server`] on a background thread and talks to it over in-memory pipes, so
+//! tests do not need to spawn the `mdpls` binary. Previews are served by recording backends, so no
+//! browser is opened, and tests can check what each preview received.
+//!
+//! ```no_run
+//! use lsp_types::Url;
+//! use mdpls::testing::Client;
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let mut client = Client::new()?;
+//!
+//! let uri = Url::parse("file:///tmp/README.md").unwrap();
+//! client.open(&uri, "# Title")?;
+//! client.replace(&uri, "# New title")?;
+//! client.close(&uri)?;
+//!
+//! assert_eq!(client.shutdown()?, 0);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Clients may run concurrently, such as in tests of the same process. If the logger is installed
+//! with [`logging::init`](crate::logging::init), each client only receives the log messages of its
+//! own server. Recording backends are not viewed on this machine, so servers do not open any
+//! sockets unless a test asks for local previews with [`Recordings::set_local`].
+//!
+//! The client can also drive an `mdpls` process, for testing the binary itself. See
+//! [`Client::spawn`].
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, prelude::*};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use lsp_types::notification::Notification as LspNotification;
+use lsp_types::request::Request as LspRequest;
+use lsp_types::{
+    lsp_notification, lsp_request, ClientCapabilities, DidChangeConfigurationParams,
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    InitializeParams, InitializeResult, InitializedParams, Range, ServerCapabilities,
+    TextDocumentContentChangeEvent, TextDocumentIdentifier, TextDocumentItem, Url,
+    VersionedTextDocumentIdentifier,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::backend::Recordings;
+use crate::protocol::{
+    LspReader, LspWriter, Message, Notification, ProtocolError, Request, ResponseError, ResultExt,
+};
+use crate::Server;
+
+/// How long the client waits for a message from the server, or for the server to exit, before
+/// failing.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often the client checks whether a server process has exited.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A language client connected to a server running in the same process, or in an `mdpls` process.
+///
+/// Messages from the server that a test has not asked for yet, such as log messages or requests
+/// for configuration, are queued until they are expected with [`Client::expect_notification`] or
+/// [`Client::expect_request`].
+///
+/// Dropping the client without calling [`Client::shutdown`] sends `exit` and waits for the server
+/// to stop. A server process that does not stop is killed.
+pub struct Client {
+    /// `None` once the connection is closed.
+    reader: Option<LspReader<PipeReader>>,
+    /// `None` once the connection is closed, or once `exit` is sent.
+    writer: Option<LspWriter<Box<dyn Write + Send>>>,
+    server: Option<ServerHandle>,
+    next_id: i64,
+    /// Notifications and requests from the server that have not been expected yet.
+    pending: VecDeque<Message>,
+    /// The version of each open document.
+    versions: HashMap<Url, i64>,
+    capabilities: ServerCapabilities,
+    recordings: Recordings,
+}
+
+impl Client {
+    /// Starts a server and initializes it with [`initialize_params`].
+    pub fn new() -> io::Result<Self> {
+        Client::with_params(initialize_params())
+    }
+
+    /// Starts a server and sends the `initialize` request and the `initialized` notification.
+    pub fn with_params(params: InitializeParams) -> io::Result<Self> {
+        Client::with_json_params(serde_json::to_value(params).expect("error serializing LSP type"))
+    }
+
+    /// Like [`Client::with_params`], but the parameters of `initialize` are JSON. This allows
+    /// testing capabilities that are newer than `lsp_types`.
+    pub fn with_json_params(params: Value) -> io::Result<Self> {
+        let (server_reader, client_writer) = pipe(None);
+        let (client_reader, server_writer) = pipe(Some(TIMEOUT));
+
+        let recordings = Recordings::default();
+        let backends = recordings.clone();
+
+        let server = thread::spawn(move || {
+            let mut server = Server::new(server_reader, server_writer);
+            server.set_preview_backend(move |uri, address| {
+                Ok(Box::new(backends.backend(uri, address)))
+            });
+            server.serve()
+        });
+
+        let mut client = Client::connect(
+            ServerHandle::Thread(server),
+            client_reader,
+            Box::new(client_writer),
+            recordings,
+        );
+
+        client
+            .initialize_with(params)?
+            .map_err(|e| io::Error::other(e.message))?;
+
+        Ok(client)
+    }
+
+    /// Runs an `mdpls` process, such as the binary started with `--test`, and talks to it over its
+    /// stdin and stdout. The server is not initialized, so that tests can send anything first.
+    ///
+    /// The previews are recorded by the other process, so [`Client::previews`] is always empty.
+    pub fn spawn(command: &mut Command) -> io::Result<Self> {
+        let mut process = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        let stdin = process.stdin.take().unwrap();
+        let stdout = process.stdout.take().unwrap();
+
+        Ok(Client::with_process(process, stdout, stdin))
+    }
+
+    /// Talks to an `mdpls` process over another transport, such as a socket that it connected to.
+    /// Like [`Client::spawn`], the server is not initialized.
+    pub fn with_process(
+        process: Child,
+        reader: impl Read + Send + 'static,
+        writer: impl Write + Send + 'static,
+    ) -> Self {
+        Client::connect(
+            ServerHandle::Process(process),
+            forward(reader),
+            Box::new(writer),
+            Recordings::default(),
+        )
+    }
+
+    fn connect(
+        server: ServerHandle,
+        reader: PipeReader,
+        writer: Box<dyn Write + Send>,
+        recordings: Recordings,
+    ) -> Self {
+        Client {
+            reader: Some(LspReader::new(reader)),
+            writer: Some(LspWriter::new(writer)),
+            server: Some(server),
+            next_id: 0,
+            pending: VecDeque::new(),
+            versions: HashMap::new(),
+            capabilities: ServerCapabilities::default(),
+            recordings,
+        }
+    }
+
+    /// Sends the `initialize` request and, if it succeeds, the `initialized` notification.
+    pub fn initialize(
+        &mut self,
+        params: InitializeParams,
+    ) -> io::Result<Result<(), ResponseError>> {
+        self.initialize_with(serde_json::to_value(params).expect("error serializing LSP type"))
+    }
+
+    fn initialize_with(&mut self, params: Value) -> io::Result<Result<(), ResponseError>> {
+        let result = match self.send_request(<lsp_request!("initialize")>::METHOD, Some(params))? {
+            Ok(result) => result,
+            Err(e) => return Ok(Err(e)),
+        };
+        self.capabilities = deserialize::<InitializeResult>(result)?.capabilities;
+
+        self.notify::<lsp_notification!("initialized")>(InitializedParams {})?;
+
+        Ok(Ok(()))
+    }
+
+    /// The capabilities that the server returned from `initialize`.
+    pub fn server_capabilities(&self) -> &ServerCapabilities {
+        &self.capabilities
+    }
+
+    /// What the server's preview backends received.
+    ///
+    /// The server handles messages in the order that they were sent, but asynchronously. Send a
+    /// request and wait for its response, such as with [`Client::request`], before checking for
+    /// the effects of earlier notifications.
+    pub fn previews(&self) -> &Recordings {
+        &self.recordings
+    }
+
+    /// Sends a request and waits for its response.
+    pub fn request<R>(&mut self, params: R::Params) -> io::Result<Result<R::Result, ResponseError>>
+    where
+        R: LspRequest,
+        R::Params: Serialize,
+        R::Result: DeserializeOwned,
+    {
+        let params = serde_json::to_value(params).expect("error serializing LSP type");
+
+        match self.send_request(R::METHOD, Some(params))? {
+            Ok(result) => Ok(Ok(deserialize(result)?)),
+            Err(e) => Ok(Err(e)),
+        }
+    }
+
+    /// Like [`Client::request`], but the method and parameters are not checked. This allows
+    /// testing invalid requests.
+    pub fn send_request(
+        &mut self,
+        method: &str,
+        params: Option<Value>,
+    ) -> io::Result<Result<Value, ResponseError>> {
+        let id = Value::from(self.next_id);
+        self.next_id += 1;
+
+        let req = Request {
+            id: id.clone(),
+            method: String::from(method),
+            params,
+        };
+        self.send(&Message::Request(req))?;
+
+        loop {
+            match self.read_message()? {
+                Message::Response(res) if res.id == id => return Ok(res.into_result()),
+                Message::Response(res) => {
+                    return Err(invalid_data(format!("unexpected response: {:?}", res)))
+                }
+                message => self.pending.push_back(message),
+            }
+        }
+    }
+
+    /// Sends a notification.
+    pub fn notify<N>(&mut self, params: N::Params) -> io::Result<()>
+    where
+        N: LspNotification,
+        N::Params: Serialize,
+    {
+        let not = Notification::new::<N>(Some(params));
+        self.send(&Message::Notification(not))
+    }
+
+    /// Sends a message without waiting for a response.
+    pub fn send(&mut self, message: &Message) -> io::Result<()> {
+        self.writer()?.encode(message)
+    }
+
+    /// Sends data that need not be a message, such as to test how the server handles invalid
+    /// data.
+    pub fn send_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+        let writer = self.writer()?.get_mut();
+        writer.write_all(bytes)?;
+        writer.flush()
+    }
+
+    /// Returns the next message from the server, including messages that were queued while
+    /// waiting for something else.
+    pub fn receive(&mut self) -> io::Result<Message> {
+        match self.pending.pop_front() {
+            Some(message) => Ok(message),
+            None => self.read_message(),
+        }
+    }
+
+    /// Waits for the server to send a notification, and returns its parameters. Other messages
+    /// are queued.
+    pub fn expect_notification<N>(&mut self) -> io::Result<N::Params>
+    where
+        N: LspNotification,
+        N::Params: DeserializeOwned,
+    {
+        let not = match self.expect(
+            |message| matches!(message, Message::Notification(not) if not.method == N::METHOD),
+        )? {
+            Message::Notification(not) => not,
+            _ => unreachable!(),
+        };
+
+        deserialize(not.params.unwrap_or(Value::Null))
+    }
+
+    /// Waits for the server to send a request, and returns its ID and parameters. Other messages
+    /// are queued. The request should be answered with [`Client::respond`].
+    pub fn expect_request<R>(&mut self) -> io::Result<(Value, R::Params)>
+    where
+        R: LspRequest,
+        R::Params: DeserializeOwned,
+    {
+        let req = match self
+            .expect(|message| matches!(message, Message::Request(req) if req.method == R::METHOD))?
+        {
+            Message::Request(req) => req,
+            _ => unreachable!(),
+        };
+
+        Ok((req.id, deserialize(req.params.unwrap_or(Value::Null))?))
+    }
+
+    /// Responds to a request from the server.
+    pub fn respond<R>(
+        &mut self,
+        id: Value,
+        result: Result<R::Result, ResponseError>,
+    ) -> io::Result<()>
+    where
+        R: LspRequest,
+        R::Result: Serialize,
+    {
+        let result =
+            result.map(|result| serde_json::to_value(result).expect("error serializing LSP type"));
+
+        self.send(&Message::Response(result.into_response(id)))
+    }
+
+    /// Opens a markdown document.
+    pub fn open(&mut self, uri: &Url, text: &str) -> io::Result<()> {
+        self.versions.insert(uri.clone(), 0);
+
+        self.notify::<lsp_notification!("textDocument/didOpen")>(DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: uri.clone(),
+                language_id: String::from("markdown"),
+                version: 0,
+                text: String::from(text),
+            },
+        })
+    }
+
+    /// Changes an open document, incrementing its version.
+    pub fn change(
+        &mut self,
+        uri: &Url,
+        content_changes: Vec<TextDocumentContentChangeEvent>,
+    ) -> io::Result<()> {
+        let version = self.versions.entry(uri.clone()).or_insert(0);
+        *version += 1;
+
+        let params = DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: uri.clone(),
+                version: Some(*version),
+            },
+            content_changes,
+        };
+
+        self.notify::<lsp_notification!("textDocument/didChange")>(params)
+    }
+
+    /// Replaces a range of an open document with new text.
+    pub fn edit(&mut self, uri: &Url, range: Range, text: &str) -> io::Result<()> {
+        self.change(
+            uri,
+            vec![TextDocumentContentChangeEvent {
+                range: Some(range),
+                range_length: None,
+                text: String::from(text),
+            }],
+        )
+    }
+
+    /// Replaces the entire text of an open document.
+    pub fn replace(&mut self, uri: &Url, text: &str) -> io::Result<()> {
+        self.change(
+            uri,
+            vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: String::from(text),
+            }],
+        )
+    }
+
+    /// Closes a document.
+    pub fn close(&mut self, uri: &Url) -> io::Result<()> {
+        self.versions.remove(uri);
+
+        self.notify::<lsp_notification!("textDocument/didClose")>(DidCloseTextDocumentParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+        })
+    }
+
+    /// Sends new settings with `workspace/didChangeConfiguration`.
+    pub fn did_change_configuration(&mut self, settings: Value) -> io::Result<()> {
+        self.notify::<lsp_notification!("workspace/didChangeConfiguration")>(
+            DidChangeConfigurationParams { settings },
+        )
+    }
+
+    /// Shuts the server down and waits for it to exit. Returns the server's exit code.
+    pub fn shutdown(mut self) -> io::Result<i32> {
+        self.request::<lsp_request!("shutdown")>(())?
+            .map_err(|e| io::Error::other(e.message))?;
+
+        self.exit()
+    }
+
+    /// Sends `exit` and waits for the server to stop. Returns the server's exit code.
+    pub fn exit(mut self) -> io::Result<i32> {
+        self.send_exit()
+    }
+
+    /// Waits for the server to stop without asking it to. Returns the server's exit code.
+    pub fn wait(mut self) -> io::Result<i32> {
+        self.join()
+    }
+
+    /// Closes the connection without sending `exit`, and waits for the server to stop. Returns the
+    /// server's exit code.
+    pub fn disconnect(mut self) -> io::Result<i32> {
+        self.reader = None;
+        self.writer = None;
+        self.join()
+    }
+
+    fn send_exit(&mut self) -> io::Result<i32> {
+        let not = Notification::new::<lsp_notification!("exit")>(None);
+        let sent = self.send(&Message::Notification(not));
+        self.writer = None;
+
+        let code = self.join()?;
+
+        // The server may have stopped before `exit` was sent.
+        sent.map(|()| code)
+    }
+
+    fn join(&mut self) -> io::Result<i32> {
+        self.server
+            .take()
+            .expect("server was already stopped")
+            .wait()
+    }
+
+    fn writer(&mut self) -> io::Result<&mut LspWriter<Box<dyn Write + Send>>> {
+        self.writer.as_mut().ok_or_else(not_connected)
+    }
+
+    /// Returns the first message, queued or newly read, that `matches` accepts.
+    fn expect(&mut self, matches: impl Fn(&Message) -> bool) -> io::Result<Message> {
+        if let Some(i) = self.pending.iter().position(&matches) {
+            return Ok(self.pending.remove(i).unwrap());
+        }
+
+        loop {
+            let message = self.read_message()?;
+
+            if matches(&message) {
+                return Ok(message);
+            }
+
+            self.pending.push_back(message);
+        }
+    }
+
+    fn read_message(&mut self) -> io::Result<Message> {
+        let reader = self.reader.as_mut().ok_or_else(not_connected)?;
+
+        match reader.decode() {
+            Ok(Some(message)) => Ok(message),
+            Ok(None) => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "server closed the connection",
+            )),
+            Err(ProtocolError::Io(e)) => Err(e),
+            Err(e) => Err(invalid_data(e.to_string())),
+        }
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        if self.server.is_some() {
+            let _ = self.send_exit();
+        }
+    }
+}
+
+/// The parameters of `initialize` that [`Client::new`] sends: no workspace, and the default client
+/// capabilities.
+pub fn initialize_params() -> InitializeParams {
+    #[allow(deprecated)]
+    InitializeParams {
+        process_id: None,
+        root_path: None,
+        root_uri: None,
+        initialization_options: None,
+        capabilities: ClientCapabilities::default(),
+        trace: None,
+        workspace_folders: None,
+        client_info: None,
+    }
+}
+
+enum ServerHandle {
+    Thread(JoinHandle<io::Result<i32>>),
+    Process(Child),
+}
+
+impl ServerHandle {
+    /// Waits for the server to stop and returns its exit code. A process that does not stop in
+    /// time is killed.
+    fn wait(self) -> io::Result<i32> {
+        let mut process = match self {
+            ServerHandle::Thread(thread) => {
+                return thread
+                    .join()
+                    .map_err(|_| io::Error::other("server panicked"))?
+            }
+            ServerHandle::Process(process) => process,
+        };
+
+        let start = Instant::now();
+
+        let status = loop {
+            if let Some(status) = process.try_wait()? {
+                break status;
+            }
+
+            if start.elapsed() > TIMEOUT {
+                process.kill()?;
+                process.wait()?;
+
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "timed out waiting for the server to exit",
+                ));
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        };
+
+        status
+            .code()
+            .ok_or_else(|| io::Error::other("server was terminated by a signal"))
+    }
+}
+
+fn deserialize<T: DeserializeOwned>(value: Value) -> io::Result<T> {
+    serde_json::from_value(value).map_err(|e| invalid_data(e.to_string()))
+}
+
+fn not_connected() -> io::Error {
+    io::Error::new(io::ErrorKind::NotConnected, "connection was closed")
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Copies the output of a server process into a pipe on a background thread, so that reads time
+/// out.
+fn forward(mut output: impl Read + Send + 'static) -> PipeReader {
+    let (reader, mut writer) = pipe(Some(TIMEOUT));
+
+    // The copy stops when the process closes its output or the client is dropped.
+    thread::spawn(move || io::copy(&mut output, &mut writer));
+
+    reader
+}
+
+/// Creates an in-memory pipe. If `timeout` is set, reads fail if no data is written within it.
+fn pipe(timeout: Option<Duration>) -> (PipeReader, PipeWriter) {
+    let (tx, rx) = mpsc::channel();
+
+    let reader = PipeReader {
+        rx,
+        timeout,
+        buf: vec![],
+        pos: 0,
+    };
+
+    (reader, PipeWriter { tx })
+}
+
+struct PipeReader {
+    rx: Receiver<Vec<u8>>,
+    timeout: Option<Duration>,
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl Read for PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos == self.buf.len() {
+            let received = match self.timeout {
+                Some(timeout) => self.rx.recv_timeout(timeout),
+                None => self.rx.recv().map_err(RecvTimeoutError::from),
+            };
+
+            self.buf = match received {
+                Ok(bytes) => bytes,
+                Err(RecvTimeoutError::Disconnected) => return Ok(0),
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "timed out waiting for a message",
+                    ))
+                }
+            };
+            self.pos = 0;
+        }
+
+        let len = buf.len().min(self.buf.len() - self.pos);
+        buf[..len].copy_from_slice(&self.buf[self.pos..self.pos + len]);
+        self.pos += len;
+
+        Ok(len)
+    }
+}
+
+struct PipeWriter {
+    tx: Sender<Vec<u8>>,
+}
+
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.tx
+            .send(buf.to_vec())
+            .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
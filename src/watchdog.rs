@@ -0,0 +1,116 @@
+//! Detection of the client's process exiting without shutting down the server.
+
+use std::thread;
+use std::time::Duration;
+
+use log::*;
+
+use crate::logging;
+
+/// How often to check whether the client's process is still running.
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Calls `on_exit` from a background thread once the process with ID `pid` has exited.
+pub fn watch_process<F>(pid: u64, on_exit: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    info!("watching client process {}", pid);
+
+    logging::spawn(move || {
+        while is_running(pid) {
+            thread::sleep(POLL_INTERVAL);
+        }
+
+        info!("client process {} exited", pid);
+        on_exit();
+    });
+}
+
+/// Returns true if the process with ID `pid` is running. If it cannot be determined whether the
+/// process is running, it is assumed to be.
+#[cfg(target_os = "linux")]
+fn is_running(pid: u64) -> bool {
+    // The process may have exited without being reaped by its parent yet. The state is the first
+    // field following the parenthesized command name.
+    match std::fs::read_to_string(format!("/proc/{}/stat", pid)) {
+        Ok(stat) => {
+            let state = stat
+                .rsplit(')')
+                .next()
+                .and_then(|fields| fields.split_whitespace().next());
+
+            !matches!(state, Some("Z") | Some("X"))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => false,
+        Err(e) => {
+            warn!("could not check client process: {}", e);
+            true
+        }
+    }
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+fn is_running(pid: u64) -> bool {
+    use std::process::Command;
+
+    match Command::new("kill").arg("-0").arg(pid.to_string()).output() {
+        Ok(output) => output.status.success(),
+        Err(e) => {
+            warn!("could not check client process: {}", e);
+            true
+        }
+    }
+}
+
+#[cfg(windows)]
+fn is_running(pid: u64) -> bool {
+    use std::process::Command;
+
+    let output = Command::new("tasklist")
+        .args(&["/FI", &format!("PID eq {}", pid), "/NH", "/FO", "CSV"])
+        .output();
+
+    match output {
+        Ok(output) => String::from_utf8_lossy(&output.stdout).contains(&format!("\"{}\"", pid)),
+        Err(e) => {
+            warn!("could not check client process: {}", e);
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::is_running;
+
+    #[test]
+    fn current_process_is_running() {
+        assert!(is_running(u64::from(process::id())));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn notify_exit() {
+        use std::process::Command;
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        use super::watch_process;
+
+        let mut child = Command::new("sleep").arg("60").spawn().unwrap();
+        let pid = u64::from(child.id());
+
+        assert!(is_running(pid));
+
+        let (tx, rx) = mpsc::channel();
+        watch_process(pid, move || tx.send(()).unwrap());
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        rx.recv_timeout(Duration::from_secs(10)).unwrap();
+    }
+}
@@ -0,0 +1,116 @@
+//! Detection of changes to files that previews depend on, such as stylesheets.
+
+use std::collections::HashMap;
+use std::fs;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use log::*;
+
+use crate::logging;
+
+/// How often to check whether the watched files have changed.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The watched files, with the time that each was last modified, if it exists.
+type Files = Arc<Mutex<HashMap<PathBuf, Option<SystemTime>>>>;
+
+/// Watches files for changes from a background thread, which exits once the watcher is dropped.
+pub struct FileWatcher {
+    files: Files,
+}
+
+impl FileWatcher {
+    /// Starts watching, calling `on_change` from the background thread whenever any of the watched
+    /// files are modified, created or removed.
+    pub fn new<F>(on_change: F) -> Self
+    where
+        F: Fn() + Send + 'static,
+    {
+        let files = Files::default();
+        let watched = Arc::downgrade(&files);
+
+        logging::spawn(move || loop {
+            thread::sleep(POLL_INTERVAL);
+
+            let files = match watched.upgrade() {
+                Some(files) => files,
+                None => break,
+            };
+
+            let mut changed = false;
+
+            for (path, modified) in files.lock().unwrap().iter_mut() {
+                let current = modified_time(path);
+
+                if current != *modified {
+                    info!("{} changed", path.display());
+                    *modified = current;
+                    changed = true;
+                }
+            }
+
+            if changed {
+                on_change();
+            }
+        });
+
+        FileWatcher { files }
+    }
+
+    /// Replaces the files that are watched.
+    pub fn watch(&self, paths: impl IntoIterator<Item = PathBuf>) {
+        let mut files = self.files.lock().unwrap();
+        let old_files = mem::take(&mut *files);
+
+        *files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = match old_files.get(&path) {
+                    Some(modified) => *modified,
+                    None => modified_time(&path),
+                };
+
+                (path, modified)
+            })
+            .collect();
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::error::Error;
+    use std::fs;
+    use std::process;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use super::FileWatcher;
+
+    #[test]
+    fn watch_file() -> Result<(), Box<dyn Error>> {
+        let path = env::temp_dir().join(format!("mdpls-watch-{}.css", process::id()));
+        fs::write(&path, "body {}")?;
+
+        let (tx, rx) = mpsc::channel();
+        let watcher = FileWatcher::new(move || tx.send(()).unwrap());
+        watcher.watch(vec![path.clone()]);
+
+        assert!(rx.recv_timeout(Duration::from_millis(1500)).is_err());
+
+        fs::remove_file(&path)?;
+        rx.recv_timeout(Duration::from_secs(5))?;
+
+        Ok(())
+    }
+}
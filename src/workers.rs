@@ -0,0 +1,176 @@
+//! Background execution of work that is too slow for the main loop.
+
+use std::io;
+use std::process::{Child, ExitStatus};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use log::*;
+
+use crate::logging;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// How often a cancellable job checks whether a process has exited.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// A fixed pool of threads that run jobs in the order that they are submitted.
+///
+/// The threads exit once the pool is dropped and all submitted jobs have finished.
+pub struct Workers {
+    jobs: mpsc::Sender<Job>,
+}
+
+impl Workers {
+    pub fn new(threads: usize) -> Self {
+        let (jobs, rx) = mpsc::channel::<Job>();
+        let rx = Arc::new(Mutex::new(rx));
+
+        for i in 0..threads {
+            let rx = Arc::clone(&rx);
+
+            thread::Builder::new()
+                .name(format!("worker-{}", i))
+                .spawn(move || loop {
+                    // The lock is released before the job runs, so other workers may take jobs.
+                    let job = match rx.lock().unwrap().recv() {
+                        Ok(job) => job,
+                        Err(_) => break,
+                    };
+
+                    job();
+                })
+                .expect("could not spawn worker thread");
+        }
+
+        Workers { jobs }
+    }
+
+    pub fn spawn<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        // Workers are started before the server runs, so jobs carry the server of the thread that
+        // submitted them.
+        if self.jobs.send(Box::new(logging::inherit(job))).is_err() {
+            error!("all worker threads have exited");
+        }
+    }
+}
+
+/// Shared flag that is set when the client cancels a request.
+///
+/// Long-running jobs should check the flag periodically and stop early if it is set.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Returns an error to return from a job that stopped because it was cancelled.
+    pub fn error(&self) -> io::Error {
+        io::Error::new(io::ErrorKind::Interrupted, "request was cancelled")
+    }
+
+    /// Waits for a process to exit. If the request is cancelled first, the process is killed and
+    /// an error is returned.
+    pub fn wait(&self, process: &mut Child) -> io::Result<ExitStatus> {
+        loop {
+            if let Some(status) = process.try_wait()? {
+                return Ok(status);
+            }
+
+            if self.is_cancelled() {
+                // The process may have exited since it was checked.
+                let _ = process.kill();
+                process.wait()?;
+
+                return Err(self.error());
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::process::Command;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::{CancellationToken, Workers};
+
+    #[test]
+    fn run_jobs() {
+        let workers = Workers::new(2);
+        let (tx, rx) = mpsc::channel();
+
+        for i in 0..4 {
+            let tx = tx.clone();
+            workers.spawn(move || tx.send(i).unwrap());
+        }
+
+        let mut results = (0..4)
+            .map(|_| rx.recv_timeout(Duration::from_secs(5)).unwrap())
+            .collect::<Vec<_>>();
+        results.sort_unstable();
+
+        assert_eq!(results, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn cancel_job() {
+        let workers = Workers::new(1);
+        let (tx, rx) = mpsc::channel();
+
+        let token = CancellationToken::default();
+        let job_token = token.clone();
+
+        workers.spawn(move || {
+            while !job_token.is_cancelled() {
+                std::thread::yield_now();
+            }
+
+            tx.send(()).unwrap();
+        });
+
+        token.cancel();
+
+        rx.recv_timeout(Duration::from_secs(5)).unwrap();
+    }
+
+    #[test]
+    fn cancel_process() -> io::Result<()> {
+        if cfg!(windows) {
+            return Ok(());
+        }
+
+        let token = CancellationToken::default();
+        let mut process = Command::new("sleep").arg("10").spawn()?;
+
+        let canceller = token.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(100));
+            canceller.cancel();
+        });
+
+        let start = Instant::now();
+        let err = token.wait(&mut process).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::Interrupted);
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        Ok(())
+    }
+}
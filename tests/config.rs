@@ -1,80 +1,15 @@
+use std::env;
 use std::error::Error;
-use std::io;
-use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::fs;
+use std::process;
 
-use assert_cmd::cargo::CommandCargoExt;
 use lsp_types::{
-    lsp_notification, lsp_request, ClientCapabilities, DidChangeConfigurationParams,
-    InitializeParams,
+    lsp_request, ClientCapabilities, ExecuteCommandParams, InitializeParams, Url,
+    WorkDoneProgressParams, WorkspaceClientCapabilities, WorkspaceFolder,
 };
-use mdpls::protocol::{LspTransport, Message, Notification, Request};
+use mdpls::testing::Client;
 use serde_json::{json, Value};
 
-struct Client {
-    transport: LspTransport<ChildStdout, ChildStdin>,
-    child: Child,
-}
-
-impl Client {
-    fn new() -> Result<Self, Box<dyn Error>> {
-        let mut command = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
-
-        let mut child = command
-            .arg("--test")
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .spawn()?;
-
-        let stdin = child.stdin.take().unwrap();
-        let stdout = child.stdout.take().unwrap();
-
-        let mut transport = LspTransport::new(stdout, stdin);
-
-        #[allow(deprecated)]
-        let req = Request::new::<lsp_request!("initialize")>(
-            json!(0),
-            Some(InitializeParams {
-                process_id: None,
-                root_path: None,
-                capabilities: ClientCapabilities::default(),
-                client_info: None,
-                initialization_options: None,
-                root_uri: None,
-                trace: None,
-                workspace_folders: None,
-            }),
-        );
-        transport.encode(&Message::Request(req))?;
-
-        // Wait for initialized response.
-        transport.decode().unwrap().unwrap();
-
-        Ok(Client { transport, child })
-    }
-
-    fn did_change_configuration(&mut self, settings: Value) -> io::Result<()> {
-        let did_change_config = Notification::new::<
-            lsp_notification!("workspace/didChangeConfiguration"),
-        >(Some(DidChangeConfigurationParams { settings }));
-
-        self.transport
-            .encode(&Message::Notification(did_change_config))?;
-
-        Ok(())
-    }
-}
-
-impl Drop for Client {
-    fn drop(&mut self) {
-        let exit_notification = Notification::new::<lsp_notification!("exit")>(None);
-        self.transport
-            .encode(&Message::Notification(exit_notification))
-            .unwrap();
-
-        assert!(self.child.wait().unwrap().success());
-    }
-}
-
 #[test]
 fn bad_type() -> Result<(), Box<dyn Error>> {
     let mut client = Client::new()?;
@@ -87,5 +22,99 @@ fn bad_type() -> Result<(), Box<dyn Error>> {
         }
     }))?;
 
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn pull_configuration() -> Result<(), Box<dyn Error>> {
+    let dir = env::temp_dir().join(format!("mdpls-config-{}", process::id()));
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join("wiki.css"), ".wiki { color: teal; }")?;
+
+    let folder = Url::from_directory_path(&dir).unwrap();
+
+    let capabilities = ClientCapabilities {
+        workspace: Some(WorkspaceClientCapabilities {
+            configuration: Some(true),
+            ..WorkspaceClientCapabilities::default()
+        }),
+        ..ClientCapabilities::default()
+    };
+
+    #[allow(deprecated)]
+    let mut client = Client::with_params(InitializeParams {
+        process_id: None,
+        root_path: None,
+        root_uri: None,
+        initialization_options: None,
+        capabilities,
+        trace: None,
+        workspace_folders: Some(vec![WorkspaceFolder {
+            uri: folder.clone(),
+            name: String::from("project"),
+        }]),
+        client_info: None,
+    })?;
+
+    let (id, params) = client.expect_request::<lsp_request!("workspace/configuration")>()?;
+
+    let scopes = params
+        .items
+        .iter()
+        .map(|item| item.scope_uri.as_deref())
+        .collect::<Vec<_>>();
+    assert_eq!(scopes, vec![None, Some(folder.as_str())]);
+    assert!(params
+        .items
+        .iter()
+        .all(|item| item.section.as_deref() == Some("markdown.preview")));
+
+    client.respond::<lsp_request!("workspace/configuration")>(
+        id,
+        Ok(vec![
+            json!({ "auto": false }),
+            json!({ "auto": false, "port": 4321, "css": "wiki.css" }),
+        ]),
+    )?;
+
+    let inside = folder.join("inside.md")?;
+    let outside = Url::parse("file:///tmp/outside.md")?;
+    client.open(&inside, "# Inside")?;
+    client.open(&outside, "# Outside")?;
+
+    let mut preview_url = |uri: &Url| -> Result<Value, Box<dyn Error>> {
+        let result =
+            client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+                command: String::from("Preview URL"),
+                arguments: vec![json!(uri)],
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            })?;
+
+        Ok(result.unwrap().unwrap())
+    };
+
+    // The folder's settings apply to documents inside of it, and its stylesheet is relative to it.
+    assert_eq!(
+        preview_url(&inside)?,
+        json!(format!("http://localhost:4321{}", inside.path()))
+    );
+    assert_eq!(
+        preview_url(&outside)?,
+        json!("http://localhost/tmp/outside.md")
+    );
+
+    let previews = client.previews().clone();
+    assert!(previews
+        .content(&inside)
+        .unwrap()
+        .contains(".wiki { color: teal; }"));
+    assert!(!previews.content(&outside).unwrap().contains(".wiki"));
+
+    assert_eq!(client.shutdown()?, 0);
+
+    fs::remove_dir_all(&dir)?;
+
     Ok(())
 }
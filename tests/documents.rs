@@ -0,0 +1,1049 @@
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{prelude::*, BufReader};
+use std::net::TcpStream;
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use assert_matches::assert_matches;
+use lsp_types::{
+    lsp_notification, lsp_request, CancelParams, ExecuteCommandParams, MessageType, NumberOrString,
+    Position, ProgressParamsValue, Range, TextDocumentIdentifier, Url, WorkDoneProgress,
+    WorkDoneProgressCancelParams, WorkDoneProgressParams,
+};
+use mdpls::backend::{BackendEvent, Recordings};
+use mdpls::lsp_ext::{CursorMoved, CursorMovedParams, ShowDocument, ShowDocumentResult};
+use mdpls::protocol::{error_codes, Message, Request};
+use mdpls::testing::Client;
+use serde_json::{json, Value};
+
+#[test]
+fn multiple_documents() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+
+    let first = Url::parse("file:///tmp/first.md")?;
+    let second = Url::parse("file:///tmp/second.md")?;
+
+    client.open(&first, "# First")?;
+    client.open(&second, "# Second")?;
+    client.replace(&first, "# First, changed")?;
+    client.close(&first)?;
+    client.replace(&second, "# Second, changed")?;
+    client.close(&second)?;
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn change_unopened_document() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+
+    let uri = Url::parse("file:///tmp/unopened.md")?;
+
+    client.replace(&uri, "# Unopened")?;
+    client.close(&uri)?;
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn incremental_changes() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+
+    let uri = Url::parse("file:///tmp/incremental.md")?;
+
+    client.open(&uri, "# Title\n\nBody")?;
+    client.edit(
+        &uri,
+        Range::new(Position::new(0, 2), Position::new(0, 7)),
+        "Heading",
+    )?;
+    client.edit(
+        &uri,
+        Range::new(Position::new(2, 4), Position::new(2, 4)),
+        " text",
+    )?;
+
+    let previews = client.previews().clone();
+    assert_eq!(client.shutdown()?, 0);
+
+    let content = previews.content(&uri).unwrap();
+    assert!(content.contains("Heading</h1>"));
+    assert!(content.contains("Body text</p>"));
+
+    Ok(())
+}
+
+#[test]
+fn external_renderer() -> Result<(), Box<dyn Error>> {
+    if cfg!(windows) {
+        return Ok(());
+    }
+
+    let mut client = Client::new()?;
+
+    let uri = Url::parse("file:///tmp/renderer.md")?;
+    client.open(&uri, "shout")?;
+
+    let mut set_renderer = |renderer: Value| -> Result<String, Box<dyn Error>> {
+        client.did_change_configuration(json!({
+            "markdown": {
+                "preview": {
+                    "renderer": renderer
+                }
+            }
+        }))?;
+
+        // Wait for the configuration to be applied.
+        let result =
+            client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+                command: String::from("Preview URL"),
+                arguments: vec![],
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            })?;
+        assert!(result.is_ok());
+
+        Ok(client.previews().content(&uri).unwrap())
+    };
+
+    // The renderer's output is sent to the backend, like the builtin renderer's.
+    assert!(set_renderer(json!(["tr", "a-z", "A-Z"]))?.contains("SHOUT"));
+
+    // Unsetting the renderer renders the document with the builtin renderer again.
+    assert!(set_renderer(Value::Null)?.contains("<p data-source-line=\"0\">shout</p>"));
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn cursor_moved() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+
+    let uri = Url::parse("file:///tmp/cursor.md")?;
+    client.open(&uri, "# Title\n\nSome\ntext\n\nMore text")?;
+
+    let mut move_cursor = |line| -> Result<Vec<u64>, Box<dyn Error>> {
+        client.notify::<CursorMoved>(CursorMovedParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            line,
+        })?;
+
+        // Wait for the notification to be handled.
+        let result =
+            client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+                command: String::from("Preview URL"),
+                arguments: vec![],
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            })?;
+        assert!(result.is_ok());
+
+        Ok(client
+            .previews()
+            .events(&uri)
+            .into_iter()
+            .filter_map(|event| match event {
+                BackendEvent::ScrolledTo(line) => Some(line),
+                _ => None,
+            })
+            .collect())
+    };
+
+    assert_eq!(move_cursor(2)?, [2]);
+
+    // Moving within the same block does not scroll the preview again.
+    assert_eq!(move_cursor(3)?, [2]);
+
+    assert_eq!(move_cursor(5)?, [2, 5]);
+
+    // Scrolling does not send the document again.
+    let sent = client
+        .previews()
+        .events(&uri)
+        .into_iter()
+        .filter(|event| matches!(event, BackendEvent::Sent(_)))
+        .count();
+    assert_eq!(sent, 1);
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn open_preview_command() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+
+    let uri = Url::parse("file:///tmp/command.md")?;
+    client.open(&uri, "# Command")?;
+
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+            command: String::from("Open Preview"),
+            arguments: vec![json!(uri)],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })?;
+    assert_eq!(result, Ok(None));
+    assert_eq!(
+        client.previews().events(&uri).last(),
+        Some(&BackendEvent::Opened {
+            url: format!("http://localhost{}", uri.path()),
+            browser: None
+        })
+    );
+
+    // Cancelling a finished request has no effect. The client sent `initialize` with ID 0.
+    client.notify::<lsp_notification!("$/cancelRequest")>(CancelParams {
+        id: NumberOrString::Number(1),
+    })?;
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn stable_port() -> Result<(), Box<dyn Error>> {
+    let first = Url::parse("file:///tmp/project/first.md")?;
+    let second = Url::parse("file:///tmp/project/second.md")?;
+
+    // Opens the documents in order, and returns the URL of each preview.
+    let preview_urls = |documents: &[&Url]| -> Result<Vec<(Url, Value)>, Box<dyn Error>> {
+        let mut client = Client::new()?;
+        client.did_change_configuration(json!({
+            "markdown": { "preview": { "stablePort": true } }
+        }))?;
+
+        let mut urls = vec![];
+
+        for &uri in documents {
+            client.open(uri, "# Stable")?;
+
+            let url = client
+                .request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+                    command: String::from("Preview URL"),
+                    arguments: vec![json!(uri)],
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                })?
+                .unwrap()
+                .unwrap();
+            urls.push((uri.clone(), url));
+        }
+
+        urls.sort_by_key(|(uri, _)| uri.to_string());
+        assert_eq!(client.shutdown()?, 0);
+
+        Ok(urls)
+    };
+
+    let urls = preview_urls(&[&first, &second])?;
+    assert_eq!(preview_urls(&[&second, &first])?, urls);
+
+    // Each document has its own port.
+    let ports = urls
+        .iter()
+        .map(|(_, url)| Url::parse(url.as_str().unwrap()).map(|url| url.port()))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert!(ports[0].is_some());
+    assert_ne!(ports[0], ports[1]);
+
+    Ok(())
+}
+
+#[test]
+fn preview_url_command() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+
+    let uri = Url::parse("file:///tmp/url.md")?;
+    client.open(&uri, "# URL")?;
+
+    let preview_url = |arguments| ExecuteCommandParams {
+        command: String::from("Preview URL"),
+        arguments,
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+
+    let url = json!("http://localhost/tmp/url.md");
+
+    let result = client
+        .request::<lsp_request!("workspace/executeCommand")>(preview_url(vec![json!(uri)]))?;
+    assert_eq!(result, Ok(Some(url.clone())));
+
+    // The active document is used if no document is given.
+    let result = client.request::<lsp_request!("workspace/executeCommand")>(preview_url(vec![]))?;
+    assert_eq!(result, Ok(Some(url)));
+
+    let unopened = json!("file:///tmp/unopened.md");
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(preview_url(vec![unopened]))?;
+    assert_eq!(result.unwrap_err().code, error_codes::INVALID_PARAMS);
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn show_document_externally() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::with_json_params(json!({
+        "processId": null,
+        "rootUri": null,
+        "capabilities": {
+            "window": {
+                "showDocument": { "support": true }
+            }
+        }
+    }))?;
+
+    let uri = Url::parse("file:///tmp/external.md")?;
+    client.open(&uri, "# External")?;
+
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+            command: String::from("Open Preview"),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })?;
+    assert_eq!(result, Ok(None));
+
+    let (id, params) = client.expect_request::<ShowDocument>()?;
+    assert_eq!(params.uri.as_str(), "http://localhost/tmp/external.md");
+    assert_eq!(params.external, Some(true));
+
+    client.respond::<ShowDocument>(id, Ok(ShowDocumentResult { success: true }))?;
+
+    // The browser is left to the client.
+    assert!(!client
+        .previews()
+        .events(&uri)
+        .iter()
+        .any(|event| matches!(event, BackendEvent::Opened { .. })));
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+/// Requests a URL from the preview event listener, as a preview page would.
+fn get(url: &str) -> Result<String, Box<dyn Error>> {
+    let url = Url::parse(url)?;
+
+    let mut conn = TcpStream::connect((url.host_str().unwrap(), url.port().unwrap()))?;
+    write!(
+        conn,
+        "GET {}?{} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        url.path(),
+        url.query().unwrap_or_default()
+    )?;
+
+    let mut response = String::new();
+    conn.read_to_string(&mut response)?;
+
+    Ok(response)
+}
+
+/// Returns the URL that a document's preview pages were most recently told to request to reveal
+/// its source.
+fn reveal_url(previews: &Recordings, uri: &Url) -> Option<String> {
+    previews
+        .events(uri)
+        .into_iter()
+        .rev()
+        .find_map(|event| match event {
+            BackendEvent::SetRevealUrl(url) => Some(url),
+            _ => None,
+        })
+        .flatten()
+}
+
+#[test]
+fn copy_preview_url_command() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+
+    let uri = Url::parse("file:///tmp/copy.md")?;
+    client.open(&uri, "# Copy")?;
+
+    let copy_preview_url = |argument| ExecuteCommandParams {
+        command: String::from("Copy Preview URL"),
+        arguments: vec![argument],
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+
+    let url = "http://localhost/tmp/copy.md";
+
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(copy_preview_url(json!(uri)))?;
+    assert_eq!(result, Ok(Some(json!(url))));
+    assert_eq!(
+        client.previews().events(&uri).last(),
+        Some(&BackendEvent::Copied(String::from(url)))
+    );
+
+    let unopened = json!("file:///tmp/unopened.md");
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(copy_preview_url(unopened))?;
+    assert_eq!(result.unwrap_err().code, error_codes::INVALID_PARAMS);
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn reveal_source() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::with_json_params(json!({
+        "processId": null,
+        "rootUri": null,
+        "capabilities": {
+            "window": {
+                "showDocument": { "support": true }
+            }
+        }
+    }))?;
+
+    client.previews().set_local(true);
+
+    let uri = Url::parse("file:///tmp/reveal.md")?;
+    client.open(&uri, "# Reveal\n\nText")?;
+
+    // Wait for the preview to be published.
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+            command: String::from("Preview URL"),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })?;
+    assert!(result.is_ok());
+
+    let reveal_url = reveal_url(client.previews(), &uri).unwrap();
+
+    // Events without the token, or for documents without previews, are ignored.
+    let forged = reveal_url.replace("token=", "token=guess");
+    assert!(get(&format!("{}1", forged))?.starts_with("HTTP/1.1 204"));
+
+    let unopened = reveal_url.replace("reveal.md", "unopened.md");
+    assert!(get(&format!("{}1", unopened))?.starts_with("HTTP/1.1 204"));
+
+    assert!(get(&format!("{}2", reveal_url))?.starts_with("HTTP/1.1 204"));
+
+    let (id, params) = client.expect_request::<ShowDocument>()?;
+    assert_eq!(params.uri, uri);
+    assert_eq!(params.take_focus, Some(true));
+    assert_eq!(
+        params.selection,
+        Some(Range::new(Position::new(2, 0), Position::new(2, 0)))
+    );
+
+    client.respond::<ShowDocument>(id, Ok(ShowDocumentResult { success: true }))?;
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn reveal_unsupported() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+    client.previews().set_local(true);
+
+    let uri = Url::parse("file:///tmp/reveal.md")?;
+    client.open(&uri, "# Reveal")?;
+
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+            command: String::from("Preview URL"),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })?;
+    assert!(result.is_ok());
+
+    // Pages are not told how to reveal sources if the client cannot show them.
+    assert_eq!(reveal_url(client.previews(), &uri), None);
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn remote_previews() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::with_json_params(json!({
+        "processId": null,
+        "rootUri": null,
+        "capabilities": {
+            "window": {
+                "showDocument": { "support": true }
+            }
+        }
+    }))?;
+
+    let uri = Url::parse("file:///tmp/remote.md")?;
+    client.open(&uri, "# Remote")?;
+
+    client.did_change_configuration(json!({
+        "markdown": {
+            "preview": {
+                "offline": true
+            }
+        }
+    }))?;
+
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+            command: String::from("Preview URL"),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })?;
+
+    // Previews that are not viewed on this machine do not use the event listener, so it is not
+    // started.
+    assert_eq!(result, Ok(Some(json!("http://localhost/tmp/remote.md"))));
+    assert_eq!(reveal_url(client.previews(), &uri), None);
+    assert!(!client
+        .previews()
+        .events(&uri)
+        .iter()
+        .any(|event| matches!(event, BackendEvent::SetPageEvents(Some(_)))));
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn reveal_public_url() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::with_json_params(json!({
+        "processId": null,
+        "rootUri": null,
+        "capabilities": {
+            "window": {
+                "showDocument": { "support": true }
+            }
+        }
+    }))?;
+
+    client.previews().set_local(true);
+
+    let uri = Url::parse("file:///tmp/public.md")?;
+    client.open(&uri, "# Public\n\n$x$")?;
+
+    let mut set_public_url =
+        |public_url: Option<&str>| -> Result<(String, Option<String>), Box<dyn Error>> {
+            client.did_change_configuration(json!({
+                "markdown": {
+                    "preview": {
+                        "offline": true,
+                        "publicUrl": public_url,
+                    }
+                }
+            }))?;
+
+            let result = client.request::<lsp_request!("workspace/executeCommand")>(
+                ExecuteCommandParams {
+                    command: String::from("Preview URL"),
+                    arguments: vec![],
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                },
+            )?;
+            let url = serde_json::from_value(result.unwrap().unwrap())?;
+
+            Ok((url, reveal_url(client.previews(), &uri)))
+        };
+
+    // Pages viewed through a public URL cannot reach the event listener.
+    let (url, reveal) = set_public_url(Some("https://example.com"))?;
+    assert_eq!(url, "https://example.com/tmp/public.md");
+    assert_eq!(reveal, None);
+
+    let (url, reveal) = set_public_url(None)?;
+    assert!(url.contains("/assets/preview.html?server="));
+    assert!(reveal.is_some());
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn headless() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+
+    client.did_change_configuration(json!({
+        "markdown": {
+            "preview": {
+                "headless": true,
+                "publicUrl": "https://example.com/preview"
+            }
+        }
+    }))?;
+
+    let uri = Url::parse("file:///tmp/headless.md")?;
+    client.open(&uri, "# Headless")?;
+
+    let params = client.expect_notification::<lsp_notification!("window/showMessage")>()?;
+    assert_eq!(params.typ, MessageType::Info);
+    assert!(params
+        .message
+        .contains("https://example.com/preview/tmp/headless.md"));
+
+    assert!(!client
+        .previews()
+        .events(&uri)
+        .iter()
+        .any(|event| matches!(event, BackendEvent::Opened { .. })));
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn close_preview_command() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+
+    let uri = Url::parse("file:///tmp/close.md")?;
+    client.open(&uri, "# Close")?;
+
+    let command = |command: &str| ExecuteCommandParams {
+        command: String::from(command),
+        arguments: vec![json!(uri)],
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(command("Close Preview"))?;
+    assert_eq!(result, Ok(None));
+    assert_eq!(
+        client.previews().events(&uri).last(),
+        Some(&BackendEvent::Closed)
+    );
+
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(command("Preview URL"))?;
+    assert_eq!(result.unwrap_err().code, error_codes::INVALID_PARAMS);
+
+    // Opening the preview again restarts it with the document's content.
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(command("Open Preview"))?;
+    assert_eq!(result, Ok(None));
+    assert!(client
+        .previews()
+        .content(&uri)
+        .unwrap()
+        .contains("Close</h1>"));
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn close_document() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+
+    client.did_change_configuration(json!({
+        "markdown": {
+            "preview": {
+                "stopWhenIdle": true
+            }
+        }
+    }))?;
+
+    let uri = Url::parse("file:///tmp/closed.md")?;
+    client.open(&uri, "# Closed")?;
+    client.close(&uri)?;
+
+    // Previews start again once a document is opened.
+    client.open(&uri, "# Reopened")?;
+
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+            command: String::from("Preview URL"),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })?;
+    assert_eq!(result, Ok(Some(json!("http://localhost/tmp/closed.md"))));
+
+    let events = client
+        .previews()
+        .events(&uri)
+        .into_iter()
+        .filter(|event| matches!(event, BackendEvent::Sent(_) | BackendEvent::Closed))
+        .collect::<Vec<_>>();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[1], BackendEvent::Closed);
+    assert_matches!(&events[2], BackendEvent::Sent(content) if content.contains("Reopened</h1>"));
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn export_html_command() -> Result<(), Box<dyn Error>> {
+    let dir = env::temp_dir().join(format!("mdpls-export-{}", process::id()));
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join("image.png"), b"png")?;
+
+    let mut client = Client::new()?;
+
+    let uri = Url::from_file_path(dir.join("export.md")).unwrap();
+    client.open(&uri, "# Export\n\n![image](image.png)")?;
+
+    let export_html = |arguments| ExecuteCommandParams {
+        command: String::from("Export HTML"),
+        arguments,
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+
+    // Relative paths are relative to the document.
+    let result = client.request::<lsp_request!("workspace/executeCommand")>(export_html(vec![
+        json!(uri),
+        json!("export.html"),
+    ]))?;
+    assert_eq!(result, Ok(None));
+
+    let html = fs::read_to_string(dir.join("export.html"))?;
+    assert!(html.contains("<title>export</title>"));
+    assert!(html.contains("Export</h1>"));
+    assert!(html.contains(r#"src="data:image/png;base64,cG5n""#));
+
+    // The output path is required.
+    let result = client
+        .request::<lsp_request!("workspace/executeCommand")>(export_html(vec![json!(uri)]))?;
+    assert_eq!(result.unwrap_err().code, error_codes::INVALID_PARAMS);
+
+    assert_eq!(client.shutdown()?, 0);
+
+    fs::remove_dir_all(&dir)?;
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn export_command() -> Result<(), Box<dyn Error>> {
+    let dir = env::temp_dir().join(format!("mdpls-exporters-{}", process::id()));
+    fs::create_dir_all(&dir)?;
+
+    let mut client = Client::new()?;
+
+    client.did_change_configuration(json!({
+        "markdown": {
+            "preview": {
+                "exporters": {
+                    "txt": {
+                        "command": ["tr", "a-z", "A-Z"],
+                        "input": "markdown"
+                    },
+                    "html": ["sh", "-c", "cat > \"$0\"", "{output}"],
+                    "fail": ["sh", "-c", "echo broken >&2; exit 1"]
+                }
+            }
+        }
+    }))?;
+
+    let uri = Url::from_file_path(dir.join("export.md")).unwrap();
+    client.open(&uri, "# Export")?;
+
+    let export = |arguments, token| ExecuteCommandParams {
+        command: String::from("Export"),
+        arguments,
+        work_done_progress_params: WorkDoneProgressParams {
+            work_done_token: token,
+        },
+    };
+
+    // The exported file is next to the document by default.
+    let token = NumberOrString::String(String::from("export"));
+    let result = client.request::<lsp_request!("workspace/executeCommand")>(export(
+        vec![json!(uri), json!("txt")],
+        Some(token.clone()),
+    ))?;
+    assert_eq!(result, Ok(None));
+    assert_eq!(fs::read_to_string(dir.join("export.txt"))?, "# EXPORT");
+
+    let progress = client.expect_notification::<lsp_notification!("$/progress")>()?;
+    assert_eq!(progress.token, token);
+    assert_matches!(
+        progress.value,
+        ProgressParamsValue::WorkDone(WorkDoneProgress::Begin(begin)) if begin.title == "Exporting export to txt"
+    );
+    let progress = client.expect_notification::<lsp_notification!("$/progress")>()?;
+    assert_matches!(
+        progress.value,
+        ProgressParamsValue::WorkDone(WorkDoneProgress::Report(_))
+    );
+    let progress = client.expect_notification::<lsp_notification!("$/progress")>()?;
+    assert_matches!(
+        progress.value,
+        ProgressParamsValue::WorkDone(WorkDoneProgress::End(_))
+    );
+
+    let result = client.request::<lsp_request!("workspace/executeCommand")>(export(
+        vec![json!(uri), json!("html"), json!("exported.html")],
+        None,
+    ))?;
+    assert_eq!(result, Ok(None));
+    assert!(fs::read_to_string(dir.join("exported.html"))?.contains("Export</h1>"));
+
+    let result = client.request::<lsp_request!("workspace/executeCommand")>(export(
+        vec![json!(uri), json!("fail")],
+        None,
+    ))?;
+    let err = result.unwrap_err();
+    assert_eq!(err.code, error_codes::INTERNAL_ERROR);
+    assert!(err.message.contains("broken"));
+
+    let result = client.request::<lsp_request!("workspace/executeCommand")>(export(
+        vec![json!(uri), json!("pdf")],
+        None,
+    ))?;
+    assert_eq!(result.unwrap_err().code, error_codes::INVALID_PARAMS);
+
+    assert_eq!(client.shutdown()?, 0);
+
+    fs::remove_dir_all(&dir)?;
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn cancel_export_progress() -> Result<(), Box<dyn Error>> {
+    let dir = env::temp_dir().join(format!("mdpls-cancel-export-{}", process::id()));
+    fs::create_dir_all(&dir)?;
+
+    let mut client = Client::new()?;
+
+    client.did_change_configuration(json!({
+        "markdown": {
+            "preview": {
+                "exporters": {
+                    "txt": {
+                        "command": ["sh", "-c", "sleep 10; cat"],
+                        "input": "markdown"
+                    }
+                }
+            }
+        }
+    }))?;
+
+    let uri = Url::from_file_path(dir.join("export.md")).unwrap();
+    client.open(&uri, "# Export")?;
+
+    // The request is sent without waiting for its response, so that it can be cancelled.
+    let id = json!("export");
+    let token = NumberOrString::String(String::from("export"));
+    let req = Request::new::<lsp_request!("workspace/executeCommand")>(
+        id.clone(),
+        Some(ExecuteCommandParams {
+            command: String::from("Export"),
+            arguments: vec![json!(uri), json!("txt")],
+            work_done_progress_params: WorkDoneProgressParams {
+                work_done_token: Some(token.clone()),
+            },
+        }),
+    );
+    client.send(&Message::Request(req))?;
+
+    let progress = client.expect_notification::<lsp_notification!("$/progress")>()?;
+    assert_matches!(
+        progress.value,
+        ProgressParamsValue::WorkDone(WorkDoneProgress::Begin(begin)) if begin.cancellable == Some(true)
+    );
+
+    client.notify::<lsp_notification!("window/workDoneProgress/cancel")>(
+        WorkDoneProgressCancelParams { token },
+    )?;
+
+    let res = loop {
+        match client.receive()? {
+            Message::Response(res) if res.id == id => break res,
+            _ => (),
+        }
+    };
+    assert_eq!(
+        res.into_result().unwrap_err().code,
+        error_codes::REQUEST_CANCELLED
+    );
+
+    // The exporter is killed, rather than left to finish.
+    let end = loop {
+        let progress = client.expect_notification::<lsp_notification!("$/progress")>()?;
+
+        if let ProgressParamsValue::WorkDone(WorkDoneProgress::End(end)) = progress.value {
+            break end;
+        }
+    };
+    assert_eq!(end.message.as_deref(), Some("Export cancelled"));
+    assert!(!dir.join("export.txt").exists());
+
+    assert_eq!(client.shutdown()?, 0);
+
+    fs::remove_dir_all(&dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn custom_page() -> Result<(), Box<dyn Error>> {
+    let dir = env::temp_dir().join(format!("mdpls-page-{}", process::id()));
+    fs::create_dir_all(&dir)?;
+
+    let css = dir.join("wiki.css");
+    let template = dir.join("wiki.html");
+    fs::write(&css, ".wiki { color: red; }")?;
+    fs::write(&template, "<header>{{title}}</header>\n{{content}}")?;
+
+    let mut client = Client::new()?;
+
+    client.did_change_configuration(json!({
+        "markdown": {
+            "preview": {
+                "css": css,
+                "template": template
+            }
+        }
+    }))?;
+
+    let uri = Url::from_file_path(dir.join("wiki.md")).unwrap();
+    client.open(&uri, "# Wiki")?;
+
+    // Wait for the document to be opened.
+    let result =
+        client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+            command: String::from("Preview URL"),
+            arguments: vec![],
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        })?;
+    assert!(result.is_ok());
+
+    assert!(client
+        .previews()
+        .events(&uri)
+        .contains(&BackendEvent::SetStylesheets(vec![css.clone()])));
+
+    let content = client.previews().content(&uri).unwrap();
+    assert!(content.contains("<header>wiki</header>"));
+    assert!(content.contains(".wiki { color: red; }"));
+    assert!(content.contains("Wiki</h1>"));
+
+    // Open previews are updated when the stylesheet changes.
+    fs::write(&css, ".wiki { color: blue; }")?;
+
+    let reloaded = (0..50).any(|_| {
+        thread::sleep(Duration::from_millis(100));
+        client
+            .previews()
+            .content(&uri)
+            .unwrap()
+            .contains(".wiki { color: blue; }")
+    });
+    assert!(reloaded);
+
+    assert_eq!(client.shutdown()?, 0);
+
+    fs::remove_dir_all(&dir)?;
+
+    Ok(())
+}
+
+#[test]
+fn offline_page() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::new()?;
+    client.previews().set_local(true);
+
+    let uri = Url::parse("file:///tmp/math.md")?;
+    client.open(&uri, "$x^2$")?;
+
+    let preview_url = |client: &mut Client| -> Result<String, Box<dyn Error>> {
+        let result =
+            client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+                command: String::from("Preview URL"),
+                arguments: vec![],
+                work_done_progress_params: WorkDoneProgressParams::default(),
+            })?;
+
+        Ok(serde_json::from_value(result.unwrap().unwrap())?)
+    };
+
+    let server_url = preview_url(&mut client)?;
+    assert_eq!(server_url, "http://localhost/tmp/math.md");
+
+    client.did_change_configuration(json!({
+        "markdown": {
+            "preview": {
+                "offline": true
+            }
+        }
+    }))?;
+
+    // The preview is viewed through a page served by mdpls, which connects to the preview server
+    // and subscribes to events from mdpls.
+    let url = Url::parse(&preview_url(&mut client)?)?;
+    assert_eq!(url.path(), "/assets/preview.html");
+    let query = url.query_pairs().into_owned().collect::<HashMap<_, _>>();
+    assert_eq!(query["server"], server_url);
+
+    // Nothing is loaded from the network.
+    let page = get(url.as_str())?;
+    assert!(page.starts_with("HTTP/1.1 200"));
+    assert!(page.contains("katex.min.js"));
+    assert!(page.contains("github-markdown.css"));
+    assert!(!page.contains("https://"));
+
+    // The page links the preview server's stylesheets, so only the content is sent.
+    let sent = client
+        .previews()
+        .events(&uri)
+        .into_iter()
+        .filter(|event| matches!(event, BackendEvent::Sent(_)))
+        .count();
+    assert_eq!(sent, 1);
+    assert!(!client
+        .previews()
+        .content(&uri)
+        .unwrap()
+        .contains(".markdown-body"));
+
+    // The page is told which highlight.js style to link.
+    let events = Url::parse(&query["events"])?;
+    let conn = TcpStream::connect((events.host_str().unwrap(), events.port().unwrap()))?;
+    conn.set_read_timeout(Some(Duration::from_secs(5)))?;
+    write!(
+        &conn,
+        "GET {}?{} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        events.path(),
+        events.query().unwrap()
+    )?;
+
+    let mut lines = BufReader::new(conn).lines();
+    assert!(lines.any(|line| line.unwrap() == "data: github"));
+
+    client.did_change_configuration(json!({
+        "markdown": {
+            "preview": {
+                "offline": true,
+                "codeTheme": "darcula"
+            }
+        }
+    }))?;
+    assert!(lines.any(|line| line.unwrap() == "data: darcula"));
+
+    let style = get(url.join("highlight.js/styles/darcula.css")?.as_str())?;
+    assert!(style.starts_with("HTTP/1.1 200"));
+    assert!(style.contains("Content-Type: text/css"));
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
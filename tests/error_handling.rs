@@ -1,35 +1,49 @@
+use std::env;
 use std::error::Error;
-use std::io::prelude::*;
-use std::io::BufReader;
-use std::process::{Command, Stdio};
+use std::fs;
+use std::process::{self, Command, Stdio};
 
 use assert_cmd::cargo::CommandCargoExt;
 use assert_matches::assert_matches;
+use lsp_types::notification::Notification as LspNotification;
+use lsp_types::{lsp_notification, lsp_request, MessageType, Url};
+use serde::Deserialize;
+use serde_json::json;
+
+use mdpls::protocol::{error_codes, Message, Notification, ResponseError};
+use mdpls::testing::{initialize_params, Client};
+
+fn spawn() -> Result<Client, Box<dyn Error>> {
+    Ok(Client::spawn(&mut Command::cargo_bin(env!(
+        "CARGO_PKG_NAME"
+    ))?)?)
+}
 
-use mdpls::protocol::{LspTransport, Message, ResponseError};
-
-#[test]
-fn not_http() -> Result<(), Box<dyn Error>> {
-    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+/// Spawns a server and initializes it.
+fn initialized_server() -> Result<Client, Box<dyn Error>> {
+    initialized_server_with_args(&[])
+}
 
-    let mut child = cmd
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::null())
-        .spawn()
-        .unwrap();
+fn initialized_server_with_args(args: &[&str]) -> Result<Client, Box<dyn Error>> {
+    let mut client = Client::spawn(
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+            .arg("--test")
+            .args(args),
+    )?;
 
-    let mut stdin = child.stdin.take().unwrap();
-    let stdout = BufReader::new(child.stdout.take().unwrap());
+    client.initialize(initialize_params())?.unwrap();
 
-    write!(stdin, "not http")?;
-    stdin.flush()?;
+    Ok(client)
+}
 
-    let mut transport = LspTransport::new(stdout, stdin);
+#[test]
+fn not_http() -> Result<(), Box<dyn Error>> {
+    let mut client =
+        Client::spawn(Command::cargo_bin(env!("CARGO_PKG_NAME"))?.stderr(Stdio::null()))?;
 
-    let message = transport.decode()?;
+    client.send_bytes(b"not http")?;
 
-    let res = assert_matches!(message, Some(Message::Response(res)) => res);
+    let res = assert_matches!(client.receive()?, Message::Response(res) => res);
 
     assert_eq!(
         res.into_result(),
@@ -40,35 +54,19 @@ fn not_http() -> Result<(), Box<dyn Error>> {
         })
     );
 
-    drop(transport);
-
-    assert!(!child.wait()?.success());
+    assert_ne!(client.disconnect()?, 0);
 
     Ok(())
 }
 
 #[test]
 fn not_json() -> Result<(), Box<dyn Error>> {
-    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    let mut client = spawn()?;
 
-    let mut child = cmd
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .spawn()
-        .unwrap();
+    client.send_bytes(b"Content-Length: 8\r\n\r\n")?;
+    client.send_bytes(b"not json")?;
 
-    let mut stdin = child.stdin.take().unwrap();
-    let stdout = BufReader::new(child.stdout.take().unwrap());
-
-    write!(stdin, "Content-Length: 8\r\n\r\n")?;
-    write!(stdin, "not json")?;
-    stdin.flush()?;
-
-    let mut transport = LspTransport::new(stdout, stdin);
-
-    let message = transport.decode()?;
-
-    let res = assert_matches!(message, Some(Message::Response(res)) => res);
+    let res = assert_matches!(client.receive()?, Message::Response(res) => res);
 
     assert_eq!(
         res.into_result(),
@@ -79,9 +77,157 @@ fn not_json() -> Result<(), Box<dyn Error>> {
         })
     );
 
-    drop(transport);
+    assert_eq!(client.disconnect()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn unknown_response() -> Result<(), Box<dyn Error>> {
+    let mut client = spawn()?;
+
+    let response = Message::deserialize(json!({ "jsonrpc": "2.0", "id": 1337, "result": null }))?;
+    client.send(&response)?;
+
+    assert_eq!(client.disconnect()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn unknown_method() -> Result<(), Box<dyn Error>> {
+    let mut client = initialized_server()?;
+
+    let err = client
+        .send_request("textDocument/unknown", None)?
+        .unwrap_err();
 
-    assert!(child.wait()?.success());
+    assert_eq!(err.code, error_codes::METHOD_NOT_FOUND);
+
+    assert_eq!(client.disconnect()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_request_params() -> Result<(), Box<dyn Error>> {
+    let mut client = initialized_server()?;
+
+    let err = client
+        .send_request(
+            "workspace/executeCommand",
+            Some(json!({ "arguments": "not a list" })),
+        )?
+        .unwrap_err();
+    assert_eq!(err.code, error_codes::INVALID_PARAMS);
+
+    let err = client
+        .send_request(
+            "workspace/executeCommand",
+            Some(json!({ "command": "Open Preview", "arguments": [1337] })),
+        )?
+        .unwrap_err();
+    assert_eq!(err.code, error_codes::INVALID_PARAMS);
+
+    assert_eq!(client.disconnect()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_notification_params() -> Result<(), Box<dyn Error>> {
+    let mut client = initialized_server()?;
+
+    let not = Notification {
+        method: String::from(<lsp_notification!("textDocument/didOpen")>::METHOD),
+        params: Some(json!({ "textDocument": "not a document" })),
+    };
+    client.send(&Message::Notification(not))?;
+
+    let params = client.expect_notification::<lsp_notification!("window/logMessage")>()?;
+    assert_eq!(params.typ, MessageType::Error);
+
+    // The server is still running.
+    client.request::<lsp_request!("shutdown")>(())?.unwrap();
+
+    assert_eq!(client.disconnect()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn log_file() -> Result<(), Box<dyn Error>> {
+    let path = env::temp_dir().join(format!("mdpls-{}.log", process::id()));
+    let mut client = initialized_server_with_args(&["--log-file", path.to_str().unwrap()])?;
+
+    let not = Notification {
+        method: String::from(<lsp_notification!("textDocument/didOpen")>::METHOD),
+        params: None,
+    };
+    client.send(&Message::Notification(not))?;
+
+    client.request::<lsp_request!("shutdown")>(())?.unwrap();
+
+    assert_eq!(client.disconnect()?, 0);
+
+    let log = fs::read_to_string(&path)?;
+    fs::remove_file(&path)?;
+
+    assert!(log.contains("could not handle textDocument/didOpen"));
+
+    Ok(())
+}
+
+#[test]
+fn concurrent_in_process_servers() -> Result<(), Box<dyn Error>> {
+    mdpls::logging::init();
+
+    let mut first = Client::new()?;
+    let mut second = Client::new()?;
+
+    let invalid = |method: &str| {
+        Message::Notification(Notification {
+            method: String::from(method),
+            params: Some(json!({ "textDocument": "not a document" })),
+        })
+    };
+
+    first.send(&invalid("textDocument/didOpen"))?;
+    let params = first.expect_notification::<lsp_notification!("window/logMessage")>()?;
+    assert!(params.message.contains("textDocument/didOpen"));
+
+    // Errors are only forwarded to the client of the server that logged them, so the second
+    // client's first message is about its own error.
+    second.send(&invalid("textDocument/didClose"))?;
+    let params = second.expect_notification::<lsp_notification!("window/logMessage")>()?;
+    assert!(params.message.contains("textDocument/didClose"));
+
+    assert_eq!(first.shutdown()?, 0);
+    assert_eq!(second.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn offline_unavailable() -> Result<(), Box<dyn Error>> {
+    let mut client = initialized_server()?;
+
+    client.did_change_configuration(json!({
+        "markdown": {
+            "preview": {
+                "offline": true,
+                "publicUrl": "https://example.com"
+            }
+        }
+    }))?;
+    client.open(&Url::parse("file:///tmp/offline.md")?, "# Offline")?;
+
+    // The preview falls back to the preview server's page, which loads KaTeX from a CDN.
+    let params = client.expect_notification::<lsp_notification!("window/logMessage")>()?;
+    assert_eq!(params.typ, MessageType::Warning);
+    assert!(params.message.contains("offline"));
+
+    assert_eq!(client.shutdown()?, 0);
 
     Ok(())
 }
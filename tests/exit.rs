@@ -1,74 +1,52 @@
-use std::io::BufReader;
-use std::process::{Command, Stdio};
-
-use mdpls::protocol::{LspTransport, Message, Notification, Request};
+use std::process::Command;
 
 use assert_cmd::cargo::CommandCargoExt;
-use lsp_types::{
-    lsp_notification, lsp_request, ClientCapabilities, InitializeParams, InitializedParams,
-};
+use lsp_types::{lsp_request, InitializeParams};
 use serde_json::json;
 
+use mdpls::protocol::{Message, Request};
+use mdpls::testing::{initialize_params, Client};
+
+fn spawn() -> Client {
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    Client::spawn(cmd.arg("--test")).unwrap()
+}
+
 #[test]
 fn exit() {
-    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+    let mut client = spawn();
 
-    let mut child = cmd
-        .arg("--test")
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .spawn()
-        .unwrap();
+    client.initialize(initialize_params()).unwrap().unwrap();
 
-    let stdin = child.stdin.take().unwrap();
-    let stdout = BufReader::new(child.stdout.take().unwrap());
+    assert_eq!(client.shutdown().unwrap(), 0);
+}
+
+#[test]
+fn exit_without_shutdown() {
+    let client = spawn();
 
-    let mut transport = LspTransport::new(stdout, stdin);
+    assert_eq!(client.exit().unwrap(), 1);
+}
+
+#[cfg(unix)]
+#[test]
+fn client_process_exited() {
+    // Spawn a process and wait for it to exit, so that its ID is not in use.
+    let mut client_process = Command::new("true").spawn().unwrap();
+    client_process.wait().unwrap();
 
-    #[allow(deprecated)]
+    let mut client = spawn();
+
+    // The request is sent directly, since the server may exit before it could be answered.
     let req = Request::new::<lsp_request!("initialize")>(
         json!(0),
         Some(InitializeParams {
-            process_id: None,
-            root_path: None,
-            capabilities: ClientCapabilities::default(),
-            client_info: None,
-            initialization_options: None,
-            root_uri: None,
-            trace: None,
-            workspace_folders: None,
+            process_id: Some(u64::from(client_process.id())),
+            ..initialize_params()
         }),
     );
+    client.send(&Message::Request(req)).unwrap();
 
-    transport.encode(&Message::Request(req)).unwrap();
-
-    let res = match transport.decode().unwrap().unwrap() {
-        Message::Response(res) if res.id == json!(0) => res,
-        message => panic!("unexpected message: {:?}", message),
-    };
-
-    res.into_result().unwrap();
-
-    let not = Notification::new::<lsp_notification!("initialized")>(Some(InitializedParams {}));
-
-    transport.encode(&Message::Notification(not)).unwrap();
-
-    let shutdown_req = Request::new::<lsp_request!("shutdown")>(json!(1), None);
-    transport.encode(&Message::Request(shutdown_req)).unwrap();
-
-    let res = match transport.decode().unwrap().unwrap() {
-        Message::Response(res) if res.id == json!(1) => res,
-        message => panic!("unexpected message: {:?}", message),
-    };
-
-    res.into_result().unwrap();
-
-    let exit_notification = Notification::new::<lsp_notification!("exit")>(None);
-    transport
-        .encode(&Message::Notification(exit_notification))
-        .unwrap();
-
-    assert!(child.wait().unwrap().success());
-
-    let _ = transport;
+    // The server exits without being asked to.
+    assert_eq!(client.wait().unwrap(), 1);
 }
@@ -0,0 +1,73 @@
+use std::error::Error;
+use std::process::Command;
+
+use assert_cmd::cargo::CommandCargoExt;
+use lsp_types::{lsp_request, ExecuteCommandParams, Url, WorkDoneProgressParams};
+use serde_json::json;
+
+use mdpls::protocol::{error_codes, ResponseError};
+use mdpls::testing::{initialize_params, Client};
+
+fn spawn() -> Result<Client, Box<dyn Error>> {
+    Ok(Client::spawn(
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))?.arg("--test"),
+    )?)
+}
+
+fn open_preview(client: &mut Client) -> Result<ResponseError, Box<dyn Error>> {
+    let res = client.request::<lsp_request!("workspace/executeCommand")>(ExecuteCommandParams {
+        command: String::from("Open Preview"),
+        arguments: vec![json!(Url::parse("file:///tmp/test.md")?)],
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    })?;
+
+    Ok(res.unwrap_err())
+}
+
+#[test]
+fn request_before_initialize() -> Result<(), Box<dyn Error>> {
+    let mut client = spawn()?;
+
+    assert_eq!(
+        open_preview(&mut client)?.code,
+        error_codes::SERVER_NOT_INITIALIZED
+    );
+
+    // The server can still be initialized afterwards.
+    client.initialize(initialize_params())?.unwrap();
+
+    assert_eq!(client.exit()?, 1);
+
+    Ok(())
+}
+
+#[test]
+fn initialize_twice() -> Result<(), Box<dyn Error>> {
+    let mut client = spawn()?;
+
+    client.initialize(initialize_params())?.unwrap();
+
+    let err = client.initialize(initialize_params())?.unwrap_err();
+    assert_eq!(err.code, error_codes::INVALID_REQUEST);
+
+    assert_eq!(client.exit()?, 1);
+
+    Ok(())
+}
+
+#[test]
+fn request_after_shutdown() -> Result<(), Box<dyn Error>> {
+    let mut client = spawn()?;
+
+    client.initialize(initialize_params())?.unwrap();
+    client.request::<lsp_request!("shutdown")>(())?.unwrap();
+
+    assert_eq!(
+        open_preview(&mut client)?.code,
+        error_codes::INVALID_REQUEST
+    );
+
+    assert_eq!(client.exit()?, 0);
+
+    Ok(())
+}
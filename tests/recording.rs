@@ -0,0 +1,121 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::Cursor;
+use std::process::{self, Command, Stdio};
+
+use assert_cmd::cargo::CommandCargoExt;
+use serde_json::json;
+
+use mdpls::protocol::{LspTransport, Message};
+use mdpls::recording::{self, Direction};
+use mdpls::testing::{initialize_params, Client};
+
+#[test]
+fn record_and_replay() -> Result<(), Box<dyn Error>> {
+    let path = env::temp_dir().join(format!("mdpls-session-{}.jsonl", process::id()));
+
+    let mut client = Client::spawn(
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+            .arg("--test")
+            .arg("--record")
+            .arg(&path),
+    )?;
+
+    client.initialize(initialize_params())?.unwrap();
+    assert_eq!(client.shutdown()?, 0);
+
+    let entries = recording::read(&path)?;
+    let directions = entries
+        .iter()
+        .map(|entry| entry.direction)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        directions,
+        vec![
+            Direction::Received,
+            Direction::Sent,
+            Direction::Received,
+            Direction::Received,
+            Direction::Sent,
+            Direction::Received,
+        ]
+    );
+
+    let output = Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+        .arg("replay")
+        .arg(&path)
+        .stdin(Stdio::null())
+        .output()?;
+
+    fs::remove_file(&path)?;
+
+    assert!(output.status.success());
+
+    // The replayed session produces the same responses.
+    let mut transport = LspTransport::new(Cursor::new(output.stdout), Vec::new());
+
+    for id in 0..2 {
+        match transport.decode()? {
+            Some(Message::Response(res)) => assert_eq!(res.id, json!(id)),
+            message => panic!("expected response, got {:?}", message),
+        }
+    }
+
+    assert!(transport.decode()?.is_none());
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn replay_exited_client() -> Result<(), Box<dyn Error>> {
+    // Spawn a process and wait for it to exit, so that its ID is not in use.
+    let mut client_process = Command::new("true").spawn()?;
+    client_process.wait()?;
+
+    let path = env::temp_dir().join(format!("mdpls-exited-{}.jsonl", process::id()));
+
+    let messages = [
+        json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "method": "initialize",
+            "params": { "processId": client_process.id(), "rootUri": null, "capabilities": {} },
+        }),
+        json!({ "jsonrpc": "2.0", "method": "initialized", "params": {} }),
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "shutdown" }),
+        json!({ "jsonrpc": "2.0", "method": "exit" }),
+    ];
+
+    let recording = messages
+        .iter()
+        .map(|message| {
+            json!({ "timestamp": 0, "direction": "received", "message": message }).to_string()
+                + "\n"
+        })
+        .collect::<String>();
+    fs::write(&path, recording)?;
+
+    let output = Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+        .arg("replay")
+        .arg(&path)
+        .stdin(Stdio::null())
+        .output()?;
+
+    fs::remove_file(&path)?;
+
+    // The server does not exit because the recorded client is no longer running.
+    assert!(output.status.success());
+
+    let mut transport = LspTransport::new(Cursor::new(output.stdout), Vec::new());
+
+    for id in 0..2 {
+        match transport.decode()? {
+            Some(Message::Response(res)) => assert_eq!(res.id, json!(id)),
+            message => panic!("expected response, got {:?}", message),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use std::error::Error;
+use std::process::Command;
+
+use assert_cmd::cargo::CommandCargoExt;
+use assert_matches::assert_matches;
+use lsp_types::notification::Notification as LspNotification;
+use lsp_types::{lsp_notification, lsp_request, InitializeParams, TraceOption};
+use serde_json::json;
+
+use mdpls::lsp_ext::{LogTrace, LogTraceParams, SetTrace, SetTraceParams};
+use mdpls::protocol::{Message, Notification, Request};
+use mdpls::testing::{initialize_params, Client};
+
+fn next_trace(client: &mut Client) -> Result<LogTraceParams, Box<dyn Error>> {
+    let not = assert_matches!(client.receive()?, Message::Notification(not) => not);
+    assert_eq!(not.method, LogTrace::METHOD);
+    Ok(serde_json::from_value(not.params.unwrap())?)
+}
+
+#[test]
+fn trace_messages() -> Result<(), Box<dyn Error>> {
+    let mut client = Client::spawn(Command::cargo_bin(env!("CARGO_PKG_NAME"))?.arg("--test"))?;
+
+    // Messages are sent directly, so that the order of traces and responses can be checked.
+    let req = Request::new::<lsp_request!("initialize")>(
+        json!(0),
+        Some(InitializeParams {
+            trace: Some(TraceOption::Messages),
+            ..initialize_params()
+        }),
+    );
+    client.send(&Message::Request(req))?;
+
+    // Tracing starts once the request is handled.
+    let trace = next_trace(&mut client)?;
+    assert_eq!(trace.message, "Sending response '0'.");
+    assert_eq!(trace.verbose, None);
+
+    assert_matches!(client.receive()?, Message::Response(_));
+
+    let not = Notification::new::<SetTrace>(Some(SetTraceParams {
+        value: TraceOption::Verbose,
+    }));
+    client.send(&Message::Notification(not))?;
+
+    // The level is changed after the notification is traced.
+    let trace = next_trace(&mut client)?;
+    assert_eq!(trace.message, "Received notification '$/setTrace'.");
+    assert_eq!(trace.verbose, None);
+
+    let req = Request::new::<lsp_request!("shutdown")>(json!(1), None);
+    client.send(&Message::Request(req))?;
+
+    let trace = next_trace(&mut client)?;
+    assert_eq!(trace.message, "Received request 'shutdown - (1)'.");
+    assert!(trace.verbose.unwrap().contains(r#""method": "shutdown""#));
+
+    let trace = next_trace(&mut client)?;
+    assert_eq!(trace.message, "Sending response '1'.");
+    assert!(trace.verbose.is_some());
+
+    assert_matches!(client.receive()?, Message::Response(_));
+
+    let not = Notification::new::<lsp_notification!("exit")>(None);
+    client.send(&Message::Notification(not))?;
+
+    let trace = next_trace(&mut client)?;
+    assert_eq!(trace.message, "Received notification 'exit'.");
+
+    assert_eq!(client.wait()?, 0);
+
+    Ok(())
+}
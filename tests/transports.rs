@@ -0,0 +1,58 @@
+use std::error::Error;
+use std::net::TcpListener;
+use std::process::{Child, Command, Stdio};
+
+use assert_cmd::cargo::CommandCargoExt;
+
+use mdpls::testing::{initialize_params, Client};
+
+fn spawn(args: &[&str]) -> Result<Child, Box<dyn Error>> {
+    let mut command = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    Ok(command
+        .arg("--test")
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .spawn()?)
+}
+
+/// Initializes the server, shuts it down and exits.
+fn run_session(mut client: Client) -> Result<(), Box<dyn Error>> {
+    client.initialize(initialize_params())?.unwrap();
+
+    assert_eq!(client.shutdown()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn socket() -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+
+    let child = spawn(&[&format!("--socket={}", port)])?;
+
+    let (stream, _) = listener.accept()?;
+    run_session(Client::with_process(child, stream.try_clone()?, stream))
+}
+
+#[cfg(unix)]
+#[test]
+fn pipe() -> Result<(), Box<dyn Error>> {
+    use std::os::unix::net::UnixListener;
+
+    let path = std::env::temp_dir().join(format!("mdpls-test-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let listener = UnixListener::bind(&path)?;
+
+    let child = spawn(&["--pipe", path.to_str().unwrap()])?;
+
+    let (stream, _) = listener.accept()?;
+    run_session(Client::with_process(child, stream.try_clone()?, stream))?;
+
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}